@@ -1,7 +1,11 @@
+mod crypto;
 mod error;
+mod paginate;
 mod response;
 mod types;
 
+pub(crate) use crypto::*;
 pub use error::*;
+pub(crate) use paginate::*;
 pub(crate) use response::*;
 pub use types::*;
@@ -0,0 +1,82 @@
+//! Cryptographic primitives, backed by `ring` when the `rustls-tls` feature is enabled
+//! and by `openssl` when the `native-tls` feature is enabled.
+
+#[cfg(not(any(feature = "rustls-tls", feature = "native-tls")))]
+compile_error!("either the `rustls-tls` or the `native-tls` feature must be enabled");
+
+/// Returns the SHA-256 digest of `data`.
+#[cfg(feature = "rustls-tls")]
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+    let digest = ring::digest::digest(&ring::digest::SHA256, data);
+
+    let mut output = [0; 32];
+    output.copy_from_slice(digest.as_ref());
+    output
+}
+
+/// Returns the SHA-256 digest of `data`.
+#[cfg(all(feature = "native-tls", not(feature = "rustls-tls")))]
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+    openssl::sha::sha256(data)
+}
+
+/// Fills `buf` with cryptographically secure random bytes.
+#[cfg(feature = "rustls-tls")]
+pub(crate) fn fill_random(buf: &mut [u8]) {
+    use ring::rand::SecureRandom;
+
+    ring::rand::SystemRandom::new()
+        .fill(buf)
+        .expect("failed to generate random bytes");
+}
+
+/// Fills `buf` with cryptographically secure random bytes.
+#[cfg(all(feature = "native-tls", not(feature = "rustls-tls")))]
+pub(crate) fn fill_random(buf: &mut [u8]) {
+    openssl::rand::rand_bytes(buf).expect("failed to generate random bytes");
+}
+
+/// Returns the HMAC-SHA256 tag of `data` under `key`.
+#[cfg(feature = "rustls-tls")]
+pub(crate) fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, key);
+    let tag = ring::hmac::sign(&key, data);
+
+    let mut output = [0; 32];
+    output.copy_from_slice(tag.as_ref());
+    output
+}
+
+/// Returns the HMAC-SHA256 tag of `data` under `key`.
+#[cfg(all(feature = "native-tls", not(feature = "rustls-tls")))]
+pub(crate) fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    use openssl::hash::MessageDigest;
+    use openssl::pkey::PKey;
+    use openssl::sign::Signer;
+
+    let key = PKey::hmac(key).expect("failed to create HMAC key");
+    let mut signer = Signer::new(MessageDigest::sha256(), &key).expect("failed to create signer");
+    signer.update(data).expect("failed to update signer");
+
+    let mut output = [0; 32];
+    signer
+        .sign(&mut output)
+        .expect("failed to compute HMAC tag");
+    output
+}
+
+/// Returns whether `tag` is the HMAC-SHA256 tag of `data` under `key`, in constant time.
+#[cfg(feature = "rustls-tls")]
+pub(crate) fn verify_hmac_sha256(key: &[u8], data: &[u8], tag: &[u8]) -> bool {
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, key);
+
+    ring::hmac::verify(&key, data, tag).is_ok()
+}
+
+/// Returns whether `tag` is the HMAC-SHA256 tag of `data` under `key`, in constant time.
+#[cfg(all(feature = "native-tls", not(feature = "rustls-tls")))]
+pub(crate) fn verify_hmac_sha256(key: &[u8], data: &[u8], tag: &[u8]) -> bool {
+    let expected = hmac_sha256(key, data);
+
+    tag.len() == expected.len() && openssl::memcmp::eq(&expected, tag)
+}
@@ -22,3 +22,15 @@ pub enum WorkOsError<E> {
 
 /// A WorkOS SDK result.
 pub type WorkOsResult<T, E> = Result<T, WorkOsError<E>>;
+
+impl<E> WorkOsError<E> {
+    /// Converts the operation error, keeping any other error as is.
+    pub(crate) fn map_operation<F>(self, op: impl FnOnce(E) -> F) -> WorkOsError<F> {
+        match self {
+            WorkOsError::Operation(err) => WorkOsError::Operation(op(err)),
+            WorkOsError::Unauthorized => WorkOsError::Unauthorized,
+            WorkOsError::UrlParseError(err) => WorkOsError::UrlParseError(err),
+            WorkOsError::RequestError(err) => WorkOsError::RequestError(err),
+        }
+    }
+}
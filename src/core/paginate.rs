@@ -0,0 +1,41 @@
+use std::future::Future;
+
+use futures_util::stream::{self, Stream, TryStreamExt};
+
+use crate::{PaginatedList, WorkOsResult};
+
+/// Returns a stream of the pages of a paginated list, following the `after` cursor until
+/// there are no more pages.
+///
+/// `list_page` is called with the cursor of each page to list, starting with `None` for
+/// the first page. Listing stops at the first error, which is returned as is.
+pub(crate) fn paginate<T, E, F, Fut>(list_page: F) -> impl Stream<Item = WorkOsResult<Vec<T>, E>>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = WorkOsResult<PaginatedList<T>, E>>,
+{
+    stream::try_unfold(
+        (list_page, Some(None)),
+        |(mut list_page, after)| async move {
+            let Some(after) = after else {
+                return Ok(None);
+            };
+
+            let page = list_page(after).await?;
+            let next = page.metadata.after.map(Some);
+
+            Ok(Some((page.data, (list_page, next))))
+        },
+    )
+}
+
+/// Lists all records of a paginated list.
+///
+/// See [`paginate`].
+pub(crate) async fn paginate_all<T, E, F, Fut>(list_page: F) -> WorkOsResult<Vec<T>, E>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = WorkOsResult<PaginatedList<T>, E>>,
+{
+    paginate(list_page).try_concat().await
+}
@@ -1,6 +1,7 @@
 mod api_key;
 mod paginated_list;
 mod pagination_params;
+mod provider_protocol;
 mod raw_attributes;
 mod timestamps;
 mod url_encodable_vec;
@@ -8,6 +9,7 @@ mod url_encodable_vec;
 pub use api_key::*;
 pub use paginated_list::*;
 pub use pagination_params::*;
+pub use provider_protocol::*;
 pub use raw_attributes::*;
 pub use timestamps::*;
 pub(crate) use url_encodable_vec::*;
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// The protocol family of an identity or directory provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProviderProtocol {
+    /// Security Assertion Markup Language (SAML).
+    Saml,
+
+    /// OpenID Connect (OIDC).
+    Oidc,
+
+    /// OAuth.
+    Oauth,
+
+    /// System for Cross-domain Identity Management (SCIM).
+    Scim,
+
+    /// The API of a Human Resources Information System (HRIS).
+    HrisApi,
+}
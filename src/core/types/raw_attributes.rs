@@ -1,12 +1,70 @@
 use std::collections::HashMap;
 
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
 
 /// The raw attributes returned from the Identity or Directory Provider.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RawAttributes(pub HashMap<String, Value>);
 
+impl RawAttributes {
+    /// Decodes the raw attributes into a typed struct.
+    ///
+    /// See [`OktaSamlAttributes`](crate::sso::OktaSamlAttributes) and
+    /// [`AzureScimUser`](crate::directory_sync::AzureScimUser) for ready-made structs for
+    /// common providers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use serde_json::json;
+    /// use serde::Deserialize;
+    /// use workos::RawAttributes;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Attributes {
+    ///     #[serde(rename = "employeeNumber")]
+    ///     employee_number: Option<String>,
+    /// }
+    ///
+    /// # let raw_attributes = RawAttributes(HashMap::from([(
+    /// #     "employeeNumber".to_string(),
+    /// #     json!("42"),
+    /// # )]));
+    /// let attributes: Attributes = raw_attributes.decode()?;
+    /// # assert_eq!(attributes.employee_number.as_deref(), Some("42"));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn decode<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        let attributes = self.0.clone().into_iter().collect::<Map<String, Value>>();
+
+        serde_json::from_value(Value::Object(attributes))
+    }
+}
+
+/// Deserializes a value that providers send either as a single value or as a list of
+/// values, e.g., the groups of a user that belongs to only one group.
+pub(crate) fn deserialize_one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        One(T),
+        Many(Vec<T>),
+    }
+
+    Ok(match Option::<OneOrMany<T>>::deserialize(deserializer)? {
+        Some(OneOrMany::One(value)) => vec![value],
+        Some(OneOrMany::Many(values)) => values,
+        None => Vec::new(),
+    })
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
@@ -79,4 +137,32 @@ mod test {
 
         assert_eq!(raw_attributes, RawAttributes(expected_raw_attributes))
     }
+
+    #[test]
+    fn it_decodes_raw_attributes_into_a_typed_struct() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Attributes {
+            name: String,
+            description: Option<String>,
+            manager: Option<String>,
+        }
+
+        let raw_attributes: RawAttributes = serde_json::from_str(
+            &json!({
+                "name": "Developers",
+                "description": "Software Developers"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            raw_attributes.decode::<Attributes>().unwrap(),
+            Attributes {
+                name: "Developers".to_string(),
+                description: Some("Software Developers".to_string()),
+                manager: None,
+            }
+        )
+    }
 }
@@ -2,10 +2,18 @@
 //!
 //! [WorkOS Docs: Directory Sync Guide](https://workos.com/docs/directory-sync/guide)
 
+mod deprovisioner;
+mod graph;
+mod mirror;
 mod operations;
+mod role_mapping;
 mod types;
 
+pub use deprovisioner::*;
+pub use graph::*;
+pub use mirror::*;
 pub use operations::*;
+pub use role_mapping::*;
 pub use types::*;
 
 use crate::WorkOs;
@@ -0,0 +1,945 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::directory_sync::{DirectoryUser, DirectoryUserId, DirectoryUserState};
+use crate::organizations::OrganizationId;
+use crate::user_management::{
+    DeactivateOrganizationMembership, DeleteOrganizationMembership,
+    DeleteOrganizationMembershipParams, GetUserByExternalId, GetUserByExternalIdError,
+    ListOrganizationMemberships, ListOrganizationMembershipsError,
+    ListOrganizationMembershipsParams, ListSessions, ListSessionsError, ListSessionsParams,
+    ListUsers, ListUsersParams, OrganizationMembership, OrganizationMembershipId,
+    OrganizationMembershipStatus, RevokeSession, RevokeSessionParams, Session, SessionId,
+    SessionStatus, User, UserId,
+};
+use crate::webhooks::WebhookEvent;
+use crate::{paginate_all, KnownOrUnknown, PaginationParams, WorkOs, WorkOsError, WorkOsResult};
+
+/// The reason a directory user is deprovisioned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeprovisionReason {
+    /// The directory user was deleted from the directory.
+    Deleted,
+
+    /// The directory user was suspended in the directory.
+    Suspended,
+
+    /// The directory user became inactive in the directory.
+    Inactive,
+}
+
+/// How the access of a user is removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeprovisionMode {
+    /// Deletes the organization memberships of the user.
+    Remove,
+
+    /// Deactivates the organization memberships of the user, so that they can be
+    /// reactivated if the directory user returns.
+    Deactivate,
+}
+
+/// The action a [`Deprovisioner`] policy decides on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeprovisionAction {
+    /// Deprovisions the user right away.
+    Deprovision(DeprovisionMode),
+
+    /// Leaves the user untouched for a grace period.
+    ///
+    /// The caller is responsible for calling [`Deprovisioner::deprovision`] once the
+    /// grace period has passed.
+    Defer(Duration),
+
+    /// Leaves the user untouched.
+    Skip,
+}
+
+/// How a [`Deprovisioner`] finds the user of a directory user.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum UserLookup {
+    /// Finds the user in the organization by the directory user's
+    /// [primary email](DirectoryUser::primary_email).
+    #[default]
+    Email,
+
+    /// Finds the user whose external ID is the ID of the directory user.
+    ///
+    /// A user without a membership in the directory's organization is not considered
+    /// linked to the directory user.
+    DirectoryUserId,
+
+    /// Finds the user whose external ID is the ID assigned to the directory user by the
+    /// Directory Provider.
+    ///
+    /// Since these IDs are only unique within a directory, a user without a membership in
+    /// the directory's organization is not considered linked to the directory user.
+    IdpId,
+}
+
+/// An error returned from [`Deprovisioner`].
+#[derive(Debug, Error)]
+pub enum DeprovisionError {
+    /// The directory user does not belong to an organization.
+    #[error("directory user does not belong to an organization")]
+    MissingOrganization,
+
+    /// The directory user does not have an email address to find the user by.
+    #[error("directory user does not have an email address")]
+    MissingEmail,
+
+    /// A request failed after the user was found, so their access may only have been
+    /// partially removed.
+    #[error("deprovisioning was interrupted")]
+    Interrupted {
+        /// The organization memberships and sessions that were removed before the
+        /// request failed.
+        report: Box<DeprovisionReport>,
+
+        /// The error that interrupted deprovisioning.
+        #[source]
+        source: WorkOsError<Infallible>,
+    },
+}
+
+impl From<DeprovisionError> for WorkOsError<DeprovisionError> {
+    fn from(err: DeprovisionError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+/// The outcome of deprovisioning a directory user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeprovisionOutcome {
+    /// The policy decided to leave the user untouched.
+    Skipped,
+
+    /// The policy decided to leave the user untouched for a grace period.
+    Deferred {
+        /// The grace period after which the user should be deprovisioned.
+        grace_period: Duration,
+    },
+
+    /// There is no user linked to the directory user in the directory's organization.
+    UserNotFound,
+
+    /// The access of the user was removed.
+    Deprovisioned {
+        /// The ID of the user.
+        user_id: UserId,
+
+        /// How the organization memberships of the user were removed.
+        mode: DeprovisionMode,
+
+        /// The IDs of the organization memberships that were deleted or deactivated.
+        organization_membership_ids: Vec<OrganizationMembershipId>,
+
+        /// The IDs of the sessions that were revoked.
+        revoked_session_ids: Vec<SessionId>,
+    },
+}
+
+/// A report of what a [`Deprovisioner`] did for a directory user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeprovisionReport {
+    /// The ID of the directory user.
+    pub directory_user_id: DirectoryUserId,
+
+    /// The reason the directory user was deprovisioned.
+    pub reason: DeprovisionReason,
+
+    /// The outcome of deprovisioning the directory user.
+    pub outcome: DeprovisionOutcome,
+}
+
+type DeprovisionPolicy<'a> = Box<dyn Fn(DeprovisionReason) -> DeprovisionAction + Send + Sync + 'a>;
+
+/// Removes the access of users whose directory user was deleted, suspended or became
+/// inactive.
+///
+/// The organization memberships of the user in the directory's organization are
+/// deleted, and all of the user's active sessions are revoked, since a session can
+/// be switched to another organization. By default, every directory user is
+/// deprovisioned right away; use [`with_policy`](Deprovisioner::with_policy) for a grace
+/// period or to only deactivate the organization memberships.
+///
+/// # Examples
+///
+/// ```
+/// # use workos::WorkOsResult;
+/// # use workos::directory_sync::*;
+/// # use workos::webhooks::WebhookEvent;
+/// use workos::{ApiKey, WorkOs};
+///
+/// # async fn run(event: WebhookEvent) -> WorkOsResult<(), DeprovisionError> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+///
+/// let deprovisioner = Deprovisioner::new(&workos).with_policy(|reason| match reason {
+///     DeprovisionReason::Deleted => DeprovisionAction::Deprovision(DeprovisionMode::Remove),
+///     DeprovisionReason::Suspended | DeprovisionReason::Inactive => {
+///         DeprovisionAction::Deprovision(DeprovisionMode::Deactivate)
+///     }
+/// });
+///
+/// if let Some(report) = deprovisioner.handle_event(&event).await? {
+///     println!("{}: {:?}", report.directory_user_id, report.outcome);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Deprovisioner<'a> {
+    workos: &'a WorkOs,
+    lookup: UserLookup,
+    policy: DeprovisionPolicy<'a>,
+}
+
+impl<'a> Deprovisioner<'a> {
+    /// Returns a new [`Deprovisioner`] instance for the provided WorkOS client.
+    pub fn new(workos: &'a WorkOs) -> Self {
+        Self {
+            workos,
+            lookup: UserLookup::default(),
+            policy: Box::new(|_| DeprovisionAction::Deprovision(DeprovisionMode::Remove)),
+        }
+    }
+
+    /// Sets how the user of a directory user is found.
+    pub fn with_lookup(mut self, lookup: UserLookup) -> Self {
+        self.lookup = lookup;
+        self
+    }
+
+    /// Sets the policy that decides what to do for each [`DeprovisionReason`].
+    pub fn with_policy<F>(mut self, policy: F) -> Self
+    where
+        F: Fn(DeprovisionReason) -> DeprovisionAction + Send + Sync + 'a,
+    {
+        self.policy = Box::new(policy);
+        self
+    }
+
+    /// Handles a webhook event, deprovisioning the directory user if they were deleted,
+    /// suspended or became inactive.
+    ///
+    /// Returns `None` for all other events.
+    pub async fn handle_event<TCustomAttributes: Sync>(
+        &self,
+        event: &WebhookEvent<TCustomAttributes>,
+    ) -> WorkOsResult<Option<DeprovisionReport>, DeprovisionError> {
+        let (directory_user, reason) = match event {
+            WebhookEvent::DirectoryUserDeleted(webhook) => (&webhook.0, DeprovisionReason::Deleted),
+            WebhookEvent::DirectoryUserUpdated(webhook) => {
+                let directory_user = &webhook.0.directory_user;
+                match directory_user.state {
+                    KnownOrUnknown::Known(DirectoryUserState::Suspended) => {
+                        (directory_user, DeprovisionReason::Suspended)
+                    }
+                    KnownOrUnknown::Known(DirectoryUserState::Inactive) => {
+                        (directory_user, DeprovisionReason::Inactive)
+                    }
+                    _ => return Ok(None),
+                }
+            }
+            _ => return Ok(None),
+        };
+
+        let outcome = match (self.policy)(reason) {
+            DeprovisionAction::Deprovision(mode) => {
+                return self
+                    .deprovision(directory_user, reason, mode)
+                    .await
+                    .map(Some);
+            }
+            DeprovisionAction::Defer(grace_period) => DeprovisionOutcome::Deferred { grace_period },
+            DeprovisionAction::Skip => DeprovisionOutcome::Skipped,
+        };
+
+        Ok(Some(DeprovisionReport {
+            directory_user_id: directory_user.id.clone(),
+            reason,
+            outcome,
+        }))
+    }
+
+    /// Deprovisions the user of a directory user, regardless of the policy.
+    ///
+    /// Use this to deprovision a directory user after a grace period. If a request fails
+    /// once the user is found, the error is a [`DeprovisionError::Interrupted`] with a
+    /// report of what was already removed.
+    pub async fn deprovision<TCustomAttributes: Sync>(
+        &self,
+        directory_user: &DirectoryUser<TCustomAttributes>,
+        reason: DeprovisionReason,
+        mode: DeprovisionMode,
+    ) -> WorkOsResult<DeprovisionReport, DeprovisionError> {
+        let organization_id = directory_user
+            .organization_id
+            .as_ref()
+            .ok_or(DeprovisionError::MissingOrganization)?;
+
+        let report = |outcome| DeprovisionReport {
+            directory_user_id: directory_user.id.clone(),
+            reason,
+            outcome,
+        };
+
+        let Some(user) = self.find_user(directory_user, organization_id).await? else {
+            return Ok(report(DeprovisionOutcome::UserNotFound));
+        };
+
+        let memberships = self
+            .list_all_memberships(organization_id, &user.id)
+            .await
+            .map_err(|err| err.map_operation(|err| match err {}))?;
+
+        // External IDs are only unique within a directory, so a user found by external ID
+        // that is not a member of the directory's organization belongs to someone else.
+        if memberships.is_empty() && self.lookup != UserLookup::Email {
+            return Ok(report(DeprovisionOutcome::UserNotFound));
+        }
+
+        let mut organization_membership_ids = Vec::new();
+        let mut revoked_session_ids = Vec::new();
+        let result = self
+            .remove_access(
+                memberships,
+                &user.id,
+                mode,
+                &mut organization_membership_ids,
+                &mut revoked_session_ids,
+            )
+            .await;
+
+        let report = report(DeprovisionOutcome::Deprovisioned {
+            user_id: user.id,
+            mode,
+            organization_membership_ids,
+            revoked_session_ids,
+        });
+
+        match result {
+            Ok(()) => Ok(report),
+            Err(source) => Err(DeprovisionError::Interrupted {
+                report: Box::new(report),
+                source,
+            }
+            .into()),
+        }
+    }
+
+    /// Removes the organization memberships and revokes the sessions of the user,
+    /// recording what was removed as it goes.
+    async fn remove_access(
+        &self,
+        memberships: Vec<OrganizationMembership>,
+        user_id: &UserId,
+        mode: DeprovisionMode,
+        organization_membership_ids: &mut Vec<OrganizationMembershipId>,
+        revoked_session_ids: &mut Vec<SessionId>,
+    ) -> WorkOsResult<(), Infallible> {
+        let user_management = self.workos.user_management();
+
+        for membership in memberships {
+            match mode {
+                DeprovisionMode::Remove => {
+                    user_management
+                        .delete_organization_membership(&DeleteOrganizationMembershipParams {
+                            organization_membership_id: &membership.id.to_string(),
+                        })
+                        .await
+                        .map_err(|err| err.map_operation(|err| match err {}))?;
+                }
+                DeprovisionMode::Deactivate => {
+                    if membership.status == OrganizationMembershipStatus::Inactive {
+                        continue;
+                    }
+
+                    user_management
+                        .deactivate_organization_membership(&membership.id)
+                        .await
+                        .map_err(|err| err.map_operation(|err| match err {}))?;
+                }
+            }
+
+            organization_membership_ids.push(membership.id);
+        }
+
+        let sessions = self
+            .list_all_sessions(user_id)
+            .await
+            .map_err(|err| err.map_operation(|err| match err {}))?;
+
+        for session in sessions {
+            if session.status != KnownOrUnknown::Known(SessionStatus::Active) {
+                continue;
+            }
+
+            user_management
+                .revoke_session(&RevokeSessionParams {
+                    session_id: &session.id,
+                })
+                .await
+                .map_err(|err| err.map_operation(|err| match err {}))?;
+
+            revoked_session_ids.push(session.id);
+        }
+
+        Ok(())
+    }
+
+    async fn find_user<TCustomAttributes>(
+        &self,
+        directory_user: &DirectoryUser<TCustomAttributes>,
+        organization_id: &OrganizationId,
+    ) -> WorkOsResult<Option<User>, DeprovisionError> {
+        let user_management = self.workos.user_management();
+
+        let external_id = match self.lookup {
+            UserLookup::Email => {
+                let email = directory_user
+                    .primary_email()
+                    .ok_or(DeprovisionError::MissingEmail)?;
+
+                let users = user_management
+                    .list_users(&ListUsersParams {
+                        email: Some(email),
+                        organization_id: Some(organization_id),
+                        ..Default::default()
+                    })
+                    .await
+                    .map_err(|err| err.map_operation(|err| match err {}))?;
+
+                return Ok(users.data.into_iter().next());
+            }
+            UserLookup::DirectoryUserId => directory_user.id.to_string(),
+            UserLookup::IdpId => directory_user.idp_id.clone(),
+        };
+
+        match user_management.get_user_by_external_id(&external_id).await {
+            Ok(user) => Ok(Some(user)),
+            Err(WorkOsError::Operation(GetUserByExternalIdError::NotFound)) => Ok(None),
+            Err(WorkOsError::Unauthorized) => Err(WorkOsError::Unauthorized),
+            Err(WorkOsError::UrlParseError(err)) => Err(WorkOsError::UrlParseError(err)),
+            Err(WorkOsError::RequestError(err)) => Err(WorkOsError::RequestError(err)),
+        }
+    }
+
+    async fn list_all_memberships(
+        &self,
+        organization_id: &OrganizationId,
+        user_id: &UserId,
+    ) -> WorkOsResult<Vec<OrganizationMembership>, ListOrganizationMembershipsError> {
+        paginate_all(|after| async move {
+            self.workos
+                .user_management()
+                .list_organization_memberships(&ListOrganizationMembershipsParams {
+                    organization_id,
+                    user_id: Some(user_id),
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        ..Default::default()
+                    },
+                })
+                .await
+        })
+        .await
+    }
+
+    async fn list_all_sessions(
+        &self,
+        user_id: &UserId,
+    ) -> WorkOsResult<Vec<Session>, ListSessionsError> {
+        paginate_all(|after| async move {
+            self.workos
+                .user_management()
+                .list_sessions(&ListSessionsParams {
+                    user_id,
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        ..Default::default()
+                    },
+                })
+                .await
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use mockito::{Matcher, Mock, ServerGuard};
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::webhooks::{
+        DirectoryUserDeletedWebhook, DirectoryUserUpdatedWebhook,
+        DirectoryUserWithPreviousAttributes,
+    };
+    use crate::ApiKey;
+
+    fn directory_user(state: &str) -> DirectoryUser {
+        serde_json::from_value(json!({
+            "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+            "idp_id": "2836",
+            "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+            "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+            "first_name": "Marcelina",
+            "last_name": "Davis",
+            "emails": [{
+                "primary": true,
+                "type": "work",
+                "value": "marcelina@foo-corp.com"
+            }],
+            "username": "marcelina@foo-corp.com",
+            "state": state,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z",
+            "custom_attributes": {},
+            "raw_attributes": {}
+        }))
+        .unwrap()
+    }
+
+    fn updated_event(state: &str) -> WebhookEvent {
+        WebhookEvent::DirectoryUserUpdated(DirectoryUserUpdatedWebhook(
+            DirectoryUserWithPreviousAttributes {
+                directory_user: directory_user(state),
+                previous_attributes: [("state".to_string(), json!("active"))].into(),
+            },
+        ))
+    }
+
+    async fn mock_user(server: &mut ServerGuard) -> Vec<Mock> {
+        vec![
+            server
+                .mock("GET", "/user_management/users")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("email".to_string(), "marcelina@foo-corp.com".to_string()),
+                    Matcher::UrlEncoded(
+                        "organization_id".to_string(),
+                        "org_01EZTR6WYX1A0DSE2CYMGXQ24Y".to_string(),
+                    ),
+                ]))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "data": [{
+                            "object": "user",
+                            "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                            "email": "marcelina@foo-corp.com",
+                            "first_name": "Marcelina",
+                            "last_name": "Davis",
+                            "email_verified": true,
+                            "profile_picture_url": null,
+                            "last_sign_in_at": null,
+                            "external_id": null,
+                            "metadata": {},
+                            "created_at": "2021-06-25T19:07:33.155Z",
+                            "updated_at": "2021-06-25T19:07:33.155Z"
+                        }],
+                        "list_metadata": { "before": null, "after": null }
+                    })
+                    .to_string(),
+                )
+                .create_async()
+                .await,
+            server
+                .mock("GET", "/user_management/organization_memberships")
+                .match_query(Matcher::UrlEncoded(
+                    "user_id".to_string(),
+                    "user_01E4ZCR3C56J083X43JQXF3JK5".to_string(),
+                ))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "data": [{
+                            "object": "organization_membership",
+                            "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+                            "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                            "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+                            "role": { "slug": "member" },
+                            "status": "active",
+                            "created_at": "2021-06-25T19:07:33.155Z",
+                            "updated_at": "2021-06-25T19:07:33.155Z"
+                        }],
+                        "list_metadata": { "before": null, "after": null }
+                    })
+                    .to_string(),
+                )
+                .create_async()
+                .await,
+            server
+                .mock(
+                    "GET",
+                    "/user_management/users/user_01E4ZCR3C56J083X43JQXF3JK5/sessions",
+                )
+                .match_query(Matcher::Any)
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "data": [
+                            {
+                                "object": "session",
+                                "id": "session_01H93ZY4F80QPBEZ1R5B2SHQG8",
+                                "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                                "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+                                "status": "active",
+                                "ip_address": null,
+                                "user_agent": null,
+                                "expires_at": "2021-07-25T19:07:33.155Z",
+                                "created_at": "2021-06-25T19:07:33.155Z",
+                                "updated_at": "2021-06-25T19:07:33.155Z"
+                            },
+                            {
+                                "object": "session",
+                                "id": "session_01H93ZY4F80QPBEZ1R5B2SHQG9",
+                                "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                                "organization_id": null,
+                                "status": "expired",
+                                "ip_address": null,
+                                "user_agent": null,
+                                "expires_at": "2021-06-26T19:07:33.155Z",
+                                "created_at": "2021-06-25T19:07:33.155Z",
+                                "updated_at": "2021-06-25T19:07:33.155Z"
+                            }
+                        ],
+                        "list_metadata": { "before": null, "after": null }
+                    })
+                    .to_string(),
+                )
+                .create_async()
+                .await,
+        ]
+    }
+
+    async fn mock_revoke_session(server: &mut ServerGuard) -> Mock {
+        server
+            .mock("POST", "/user_management/sessions/revoke")
+            .match_body(Matcher::Json(json!({
+                "session_id": "session_01H93ZY4F80QPBEZ1R5B2SHQG8"
+            })))
+            .with_status(200)
+            .with_body("{}")
+            .expect(1)
+            .create_async()
+            .await
+    }
+
+    fn expected_report(reason: DeprovisionReason, mode: DeprovisionMode) -> DeprovisionReport {
+        DeprovisionReport {
+            directory_user_id: DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SJ"),
+            reason,
+            outcome: DeprovisionOutcome::Deprovisioned {
+                user_id: UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"),
+                mode,
+                organization_membership_ids: vec![OrganizationMembershipId::from(
+                    "om_01E4ZCR3C56J083X43JQXF3JK5",
+                )],
+                revoked_session_ids: vec![SessionId::from("session_01H93ZY4F80QPBEZ1R5B2SHQG8")],
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn it_removes_the_memberships_and_revokes_the_sessions_of_a_deleted_directory_user() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mocks = mock_user(&mut server).await;
+        let delete_mock = server
+            .mock(
+                "DELETE",
+                "/user_management/organization_memberships/om_01E4ZCR3C56J083X43JQXF3JK5",
+            )
+            .with_status(204)
+            .create_async()
+            .await;
+        let revoke_mock = mock_revoke_session(&mut server).await;
+
+        let event = WebhookEvent::DirectoryUserDeleted(DirectoryUserDeletedWebhook(
+            directory_user("active"),
+        ));
+        let report = Deprovisioner::new(&workos)
+            .handle_event(&event)
+            .await
+            .unwrap();
+
+        delete_mock.assert_async().await;
+        revoke_mock.assert_async().await;
+        assert_eq!(
+            report,
+            Some(expected_report(
+                DeprovisionReason::Deleted,
+                DeprovisionMode::Remove
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_partial_report_when_deprovisioning_is_interrupted() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mocks = mock_user(&mut server).await;
+        let _delete_mock = server
+            .mock(
+                "DELETE",
+                "/user_management/organization_memberships/om_01E4ZCR3C56J083X43JQXF3JK5",
+            )
+            .with_status(204)
+            .create_async()
+            .await;
+        let _revoke_mock = server
+            .mock("POST", "/user_management/sessions/revoke")
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let result = Deprovisioner::new(&workos)
+            .deprovision(
+                &directory_user("active"),
+                DeprovisionReason::Deleted,
+                DeprovisionMode::Remove,
+            )
+            .await;
+
+        let Err(WorkOsError::Operation(DeprovisionError::Interrupted { report, source })) = result
+        else {
+            panic!("expected an interrupted deprovisioning, got {result:?}");
+        };
+        assert_eq!(
+            *report,
+            DeprovisionReport {
+                directory_user_id: DirectoryUserId::from(
+                    "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ"
+                ),
+                reason: DeprovisionReason::Deleted,
+                outcome: DeprovisionOutcome::Deprovisioned {
+                    user_id: UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"),
+                    mode: DeprovisionMode::Remove,
+                    organization_membership_ids: vec![OrganizationMembershipId::from(
+                        "om_01E4ZCR3C56J083X43JQXF3JK5",
+                    )],
+                    revoked_session_ids: vec![],
+                },
+            }
+        );
+        assert_matches!(source, WorkOsError::RequestError(_));
+    }
+
+    #[tokio::test]
+    async fn it_deactivates_the_memberships_of_a_suspended_directory_user_with_a_soft_policy() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mocks = mock_user(&mut server).await;
+        let deactivate_mock = server
+            .mock(
+                "PUT",
+                "/user_management/organization_memberships/om_01E4ZCR3C56J083X43JQXF3JK5/deactivate",
+            )
+            .with_status(200)
+            .with_body(
+                json!({
+                    "object": "organization_membership",
+                    "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+                    "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                    "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+                    "role": { "slug": "member" },
+                    "status": "inactive",
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let revoke_mock = mock_revoke_session(&mut server).await;
+
+        let report = Deprovisioner::new(&workos)
+            .with_policy(|_| DeprovisionAction::Deprovision(DeprovisionMode::Deactivate))
+            .handle_event(&updated_event("suspended"))
+            .await
+            .unwrap();
+
+        deactivate_mock.assert_async().await;
+        revoke_mock.assert_async().await;
+        assert_eq!(
+            report,
+            Some(expected_report(
+                DeprovisionReason::Suspended,
+                DeprovisionMode::Deactivate
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_defers_deprovisioning_during_a_grace_period() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let users_mock = server
+            .mock("GET", "/user_management/users")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let deprovisioner = Deprovisioner::new(&workos)
+            .with_policy(|_| DeprovisionAction::Defer(Duration::from_secs(24 * 60 * 60)));
+
+        let report = deprovisioner
+            .handle_event(&updated_event("inactive"))
+            .await
+            .unwrap();
+        assert_eq!(
+            report,
+            Some(DeprovisionReport {
+                directory_user_id: DirectoryUserId::from(
+                    "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ"
+                ),
+                reason: DeprovisionReason::Inactive,
+                outcome: DeprovisionOutcome::Deferred {
+                    grace_period: Duration::from_secs(24 * 60 * 60)
+                },
+            })
+        );
+
+        let report = deprovisioner
+            .handle_event(&updated_event("active"))
+            .await
+            .unwrap();
+        assert_eq!(report, None);
+
+        users_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_reports_when_no_user_has_the_external_id() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock("GET", "/user_management/users/external_id/2836")
+            .with_status(404)
+            .with_body(json!({ "message": "Not Found" }).to_string())
+            .create_async()
+            .await;
+
+        let report = Deprovisioner::new(&workos)
+            .with_lookup(UserLookup::IdpId)
+            .deprovision(
+                &directory_user("active"),
+                DeprovisionReason::Deleted,
+                DeprovisionMode::Remove,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(report.outcome, DeprovisionOutcome::UserNotFound);
+    }
+
+    #[tokio::test]
+    async fn it_leaves_a_user_with_the_external_id_in_another_organization_untouched() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _user_mock = server
+            .mock("GET", "/user_management/users/external_id/2836")
+            .with_status(200)
+            .with_body(
+                json!({
+                  "object": "user",
+                  "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                  "email": "todd@example.com",
+                  "first_name": "Todd",
+                  "last_name": "Rundgren",
+                  "email_verified": true,
+                  "profile_picture_url": null,
+                  "last_sign_in_at": null,
+                  "external_id": "2836",
+                  "metadata": {},
+                  "created_at": "2021-06-25T19:07:33.155Z",
+                  "updated_at": "2021-06-25T19:07:33.155Z"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let _memberships_mock = server
+            .mock("GET", "/user_management/organization_memberships")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(
+                    "organization_id".to_string(),
+                    "org_01EZTR6WYX1A0DSE2CYMGXQ24Y".to_string(),
+                ),
+                Matcher::UrlEncoded(
+                    "user_id".to_string(),
+                    "user_01E4ZCR3C56J083X43JQXF3JK5".to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let sessions_mock = server
+            .mock(
+                "GET",
+                "/user_management/users/user_01E4ZCR3C56J083X43JQXF3JK5/sessions",
+            )
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+        let revoke_mock = server
+            .mock("POST", "/user_management/sessions/revoke")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let report = Deprovisioner::new(&workos)
+            .with_lookup(UserLookup::IdpId)
+            .deprovision(
+                &directory_user("active"),
+                DeprovisionReason::Deleted,
+                DeprovisionMode::Remove,
+            )
+            .await
+            .unwrap();
+
+        sessions_mock.assert_async().await;
+        revoke_mock.assert_async().await;
+        assert_eq!(report.outcome, DeprovisionOutcome::UserNotFound);
+    }
+}
@@ -0,0 +1,776 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use futures_util::future::try_join;
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+
+use crate::directory_sync::{
+    DirectoryGroup, DirectoryGroupId, DirectoryGroupsFilter, DirectoryId, DirectoryUser,
+    DirectoryUserGroup, DirectoryUserId, DirectoryUsersFilter, ListDirectoryGroups,
+    ListDirectoryGroupsParams, ListDirectoryUsers, ListDirectoryUsersParams,
+};
+use crate::webhooks::WebhookEvent;
+use crate::{paginate_all, PaginationParams, WorkOs, WorkOsError, WorkOsResult};
+
+/// An in-memory graph of the users, groups and group memberships of a directory.
+///
+/// # Examples
+///
+/// ```
+/// # use workos::WorkOsResult;
+/// # use workos::directory_sync::*;
+/// use workos::{ApiKey, WorkOs};
+///
+/// # async fn run() -> WorkOsResult<(), ()> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+///
+/// let graph = DirectoryGraph::build(
+///     &workos,
+///     &DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+///     4,
+/// )
+/// .await?;
+///
+/// let engineering_admins = graph.members_of_all(&[
+///     DirectoryGroupId::from("directory_group_01E1JJS84MFPPQ3G655FHTKX6Z"),
+///     DirectoryGroupId::from("directory_group_01E64QTDNS0EGJ0FMCVY9BWGZT"),
+/// ]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectoryGraph {
+    directory_id: DirectoryId,
+    users: BTreeMap<DirectoryUserId, DirectoryUser>,
+    groups: BTreeMap<DirectoryGroupId, DirectoryGroup>,
+    members: BTreeMap<DirectoryGroupId, BTreeSet<DirectoryUserId>>,
+    memberships: BTreeMap<DirectoryUserId, BTreeSet<DirectoryGroupId>>,
+}
+
+impl DirectoryGraph {
+    /// Returns an empty graph for a directory.
+    pub fn new(directory_id: DirectoryId) -> Self {
+        Self {
+            directory_id,
+            users: BTreeMap::new(),
+            groups: BTreeMap::new(),
+            members: BTreeMap::new(),
+            memberships: BTreeMap::new(),
+        }
+    }
+
+    /// Builds the graph of a directory.
+    ///
+    /// The directory users and directory groups are listed concurrently, and the group
+    /// memberships are taken from the groups of the listed users, so building a graph
+    /// only makes two paginated listings. If the directory has groups but none of the
+    /// users list any group, the members of the groups are listed instead, with at most
+    /// `max_concurrency` groups at a time.
+    ///
+    /// `max_concurrency` only applies to this fallback; listing the directory users and
+    /// directory groups always makes exactly two concurrent listings.
+    pub async fn build(
+        workos: &WorkOs,
+        directory_id: &DirectoryId,
+        max_concurrency: usize,
+    ) -> WorkOsResult<Self, ()> {
+        let (users, groups) = try_join(
+            list_all_users(
+                workos,
+                DirectoryUsersFilter::Directory {
+                    directory: directory_id,
+                },
+            ),
+            list_all_groups(workos, directory_id),
+        )
+        .await?;
+
+        let mut graph = Self::new(directory_id.clone());
+        let users_list_groups = users.iter().any(|user| !user.groups.is_empty());
+        for user in users {
+            for group in &user.groups {
+                graph.add_membership(&group.id, &user.id);
+            }
+            graph.users.insert(user.id.clone(), user);
+        }
+        graph
+            .groups
+            .extend(groups.into_iter().map(|group| (group.id.clone(), group)));
+
+        if users_list_groups || graph.groups.is_empty() {
+            return Ok(graph);
+        }
+
+        let members = stream::iter(graph.groups.keys())
+            .map(|group_id| async move {
+                let members =
+                    list_all_users(workos, DirectoryUsersFilter::Group { group: group_id }).await?;
+
+                Ok::<_, WorkOsError<()>>((group_id.clone(), members))
+            })
+            .buffer_unordered(max_concurrency.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        for (group_id, group_members) in members {
+            for member in group_members {
+                graph.add_membership(&group_id, &member.id);
+                graph.users.entry(member.id.clone()).or_insert(member);
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Returns the ID of the directory of the graph.
+    pub fn directory_id(&self) -> &DirectoryId {
+        &self.directory_id
+    }
+
+    /// Returns the directory users, ordered by ID.
+    pub fn users(&self) -> impl Iterator<Item = &DirectoryUser> {
+        self.users.values()
+    }
+
+    /// Returns the directory groups, ordered by ID.
+    pub fn groups(&self) -> impl Iterator<Item = &DirectoryGroup> {
+        self.groups.values()
+    }
+
+    /// Returns the directory user with the provided ID.
+    pub fn user(&self, user_id: &DirectoryUserId) -> Option<&DirectoryUser> {
+        self.users.get(user_id)
+    }
+
+    /// Returns the directory group with the provided ID.
+    pub fn group(&self, group_id: &DirectoryGroupId) -> Option<&DirectoryGroup> {
+        self.groups.get(group_id)
+    }
+
+    /// Returns whether the directory user is a member of the directory group.
+    pub fn is_member(&self, user_id: &DirectoryUserId, group_id: &DirectoryGroupId) -> bool {
+        self.members
+            .get(group_id)
+            .is_some_and(|members| members.contains(user_id))
+    }
+
+    /// Returns the IDs of the members of a directory group.
+    pub fn members(&self, group_id: &DirectoryGroupId) -> BTreeSet<&DirectoryUserId> {
+        self.members
+            .get(group_id)
+            .map(|members| members.iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the IDs of the directory groups a directory user is a member of.
+    pub fn groups_of(&self, user_id: &DirectoryUserId) -> BTreeSet<&DirectoryGroupId> {
+        self.memberships
+            .get(user_id)
+            .map(|groups| groups.iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the IDs of the directory users that are a member of all of the
+    /// directory groups.
+    ///
+    /// Returns an empty set if no directory groups are provided.
+    pub fn members_of_all(&self, group_ids: &[DirectoryGroupId]) -> BTreeSet<&DirectoryUserId> {
+        let Some((first, rest)) = group_ids.split_first() else {
+            return BTreeSet::new();
+        };
+
+        let mut members = self.members(first);
+        for group_id in rest {
+            let other = self.members(group_id);
+            members.retain(|member| other.contains(member));
+        }
+
+        members
+    }
+
+    /// Returns the IDs of the directory users that are a member of any of the
+    /// directory groups.
+    pub fn members_of_any(&self, group_ids: &[DirectoryGroupId]) -> BTreeSet<&DirectoryUserId> {
+        group_ids
+            .iter()
+            .flat_map(|group_id| self.members(group_id))
+            .collect()
+    }
+
+    /// Returns the IDs of the members of `group_id` that are not a member of
+    /// `excluded_group_id`.
+    pub fn members_except(
+        &self,
+        group_id: &DirectoryGroupId,
+        excluded_group_id: &DirectoryGroupId,
+    ) -> BTreeSet<&DirectoryUserId> {
+        let excluded = self.members(excluded_group_id);
+
+        self.members(group_id)
+            .into_iter()
+            .filter(|member| !excluded.contains(member))
+            .collect()
+    }
+
+    /// Applies a `dsync.group.user_added` or `dsync.group.user_removed` webhook event to
+    /// the graph.
+    ///
+    /// The [`groups`](DirectoryUser::groups) of the stored directory user are updated to
+    /// match.
+    ///
+    /// Returns whether the graph changed. Other events, and events for other
+    /// directories, are ignored.
+    pub fn apply_event(&mut self, event: &WebhookEvent) -> bool {
+        match event {
+            WebhookEvent::DirectoryUserAddedToGroup(webhook)
+                if webhook.directory_id == self.directory_id =>
+            {
+                let mut user = webhook.user.clone();
+                if !user.groups.iter().any(|group| group.id == webhook.group.id) {
+                    user.groups.push(DirectoryUserGroup {
+                        id: webhook.group.id.clone(),
+                        name: webhook.group.name.clone(),
+                        raw_attributes: webhook.group.raw_attributes.clone(),
+                        timestamps: webhook.group.timestamps.clone(),
+                    });
+                }
+                self.users.insert(user.id.clone(), user);
+                self.groups
+                    .insert(webhook.group.id.clone(), webhook.group.clone());
+
+                self.add_membership(&webhook.group.id, &webhook.user.id)
+            }
+            WebhookEvent::DirectoryUserRemovedFromGroup(webhook)
+                if webhook.directory_id == self.directory_id =>
+            {
+                if let Some(user) = self.users.get_mut(&webhook.user.id) {
+                    user.groups.retain(|group| group.id != webhook.group.id);
+                }
+
+                self.remove_membership(&webhook.group.id, &webhook.user.id)
+            }
+            _ => false,
+        }
+    }
+
+    fn add_membership(&mut self, group_id: &DirectoryGroupId, user_id: &DirectoryUserId) -> bool {
+        self.memberships
+            .entry(user_id.clone())
+            .or_default()
+            .insert(group_id.clone());
+        self.members
+            .entry(group_id.clone())
+            .or_default()
+            .insert(user_id.clone())
+    }
+
+    fn remove_membership(
+        &mut self,
+        group_id: &DirectoryGroupId,
+        user_id: &DirectoryUserId,
+    ) -> bool {
+        if let Some(groups) = self.memberships.get_mut(user_id) {
+            groups.remove(group_id);
+        }
+        self.members
+            .get_mut(group_id)
+            .is_some_and(|members| members.remove(user_id))
+    }
+}
+
+async fn list_all_users(
+    workos: &WorkOs,
+    filter: DirectoryUsersFilter<'_>,
+) -> WorkOsResult<Vec<DirectoryUser>, ()> {
+    paginate_all(|after| async move {
+        workos
+            .directory_sync()
+            .list_directory_users(&ListDirectoryUsersParams {
+                pagination: PaginationParams {
+                    after: after.as_deref(),
+                    ..Default::default()
+                },
+                filter,
+            })
+            .await
+    })
+    .await
+}
+
+async fn list_all_groups(
+    workos: &WorkOs,
+    directory_id: &DirectoryId,
+) -> WorkOsResult<Vec<DirectoryGroup>, ()> {
+    paginate_all(|after| async move {
+        workos
+            .directory_sync()
+            .list_directory_groups(&ListDirectoryGroupsParams {
+                pagination: PaginationParams {
+                    after: after.as_deref(),
+                    ..Default::default()
+                },
+                filter: DirectoryGroupsFilter::Directory {
+                    directory: directory_id,
+                },
+            })
+            .await
+    })
+    .await
+}
+
+#[cfg(test)]
+mod test {
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::webhooks::{DirectoryUserAddedToGroupWebhook, DirectoryUserRemovedFromGroupWebhook};
+    use crate::ApiKey;
+
+    const ENGINEERING: &str = "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z";
+    const ADMINS: &str = "directory_group_01E64QTDNS0EGJ0FMCVY9BWGZT";
+    const MARCELINA: &str = "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ";
+    const ROSALINDA: &str = "directory_user_01E1JJHG10ANRA2V6PAX3GD7TE";
+
+    fn ids(ids: &[&str]) -> BTreeSet<DirectoryUserId> {
+        ids.iter().map(|id| DirectoryUserId::from(*id)).collect()
+    }
+
+    fn owned(set: BTreeSet<&DirectoryUserId>) -> BTreeSet<DirectoryUserId> {
+        set.into_iter().cloned().collect()
+    }
+
+    async fn build_graph() -> DirectoryGraph {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let users_mock = server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::UrlEncoded(
+                "directory".to_string(),
+                "directory_01ECAZ4NV9QMV47GW873HDCX74".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                      "idp_id": "2836",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "first_name": "Marcelina",
+                      "last_name": "Davis",
+                      "emails": [],
+                      "username": "marcelina@foo-corp.com",
+                      "groups": [
+                        {
+                          "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                          "name": "Engineering",
+                          "created_at": "2021-06-25T19:07:33.155Z",
+                          "updated_at": "2021-06-25T19:07:33.155Z"
+                        },
+                        {
+                          "id": "directory_group_01E64QTDNS0EGJ0FMCVY9BWGZT",
+                          "name": "Admins",
+                          "created_at": "2021-06-25T19:07:33.155Z",
+                          "updated_at": "2021-06-25T19:07:33.155Z"
+                        }
+                      ],
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "custom_attributes": {},
+                      "raw_attributes": {}
+                    },
+                    {
+                      "id": "directory_user_01E1JJHG10ANRA2V6PAX3GD7TE",
+                      "idp_id": "8953",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "first_name": "Rosalinda",
+                      "last_name": "Swift",
+                      "emails": [],
+                      "username": "rosalinda@foo-corp.com",
+                      "groups": [
+                        {
+                          "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                          "name": "Engineering",
+                          "created_at": "2021-06-25T19:07:33.155Z",
+                          "updated_at": "2021-06-25T19:07:33.155Z"
+                        }
+                      ],
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "custom_attributes": {},
+                      "raw_attributes": {}
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let groups_mock = server
+            .mock("GET", "/directory_groups")
+            .match_query(Matcher::UrlEncoded(
+                "directory".to_string(),
+                "directory_01ECAZ4NV9QMV47GW873HDCX74".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                      "idp_id": "02grqrue4294w24",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "name": "Engineering",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "raw_attributes": {}
+                    },
+                    {
+                      "id": "directory_group_01E64QTDNS0EGJ0FMCVY9BWGZT",
+                      "idp_id": "02grqrue4294w25",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "name": "Admins",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "raw_attributes": {}
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let group_members_mock = server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::Regex("group=".to_string()))
+            .expect(0)
+            .create_async()
+            .await;
+
+        let graph = DirectoryGraph::build(
+            &workos,
+            &DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+            2,
+        )
+        .await
+        .unwrap();
+
+        users_mock.assert_async().await;
+        groups_mock.assert_async().await;
+        group_members_mock.assert_async().await;
+
+        graph
+    }
+
+    #[tokio::test]
+    async fn it_builds_the_graph_and_answers_membership_queries() {
+        let graph = build_graph().await;
+
+        assert_eq!(graph.users().count(), 2);
+        assert_eq!(graph.groups().count(), 2);
+        assert!(graph.is_member(
+            &DirectoryUserId::from(ROSALINDA),
+            &DirectoryGroupId::from(ENGINEERING)
+        ));
+        assert!(!graph.is_member(
+            &DirectoryUserId::from(ROSALINDA),
+            &DirectoryGroupId::from(ADMINS)
+        ));
+        assert_eq!(
+            graph.groups_of(&DirectoryUserId::from(MARCELINA)),
+            BTreeSet::from([
+                &DirectoryGroupId::from(ENGINEERING),
+                &DirectoryGroupId::from(ADMINS)
+            ])
+        );
+
+        let engineering = DirectoryGroupId::from(ENGINEERING);
+        let admins = DirectoryGroupId::from(ADMINS);
+        assert_eq!(
+            owned(graph.members_of_all(&[engineering.clone(), admins.clone()])),
+            ids(&[MARCELINA])
+        );
+        assert_eq!(
+            owned(graph.members_of_any(&[engineering.clone(), admins.clone()])),
+            ids(&[MARCELINA, ROSALINDA])
+        );
+        assert_eq!(
+            owned(graph.members_except(&engineering, &admins)),
+            ids(&[ROSALINDA])
+        );
+        assert_eq!(graph.members_of_all(&[]), BTreeSet::new());
+    }
+
+    #[tokio::test]
+    async fn it_lists_the_members_of_each_group_when_users_do_not_list_their_groups() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _users_mock = server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::UrlEncoded(
+                "directory".to_string(),
+                "directory_01ECAZ4NV9QMV47GW873HDCX74".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                      "idp_id": "2836",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "first_name": "Marcelina",
+                      "last_name": "Davis",
+                      "emails": [],
+                      "username": "marcelina@foo-corp.com",
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "custom_attributes": {},
+                      "raw_attributes": {}
+                    },
+                    {
+                      "id": "directory_user_01E1JJHG10ANRA2V6PAX3GD7TE",
+                      "idp_id": "8953",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "first_name": "Rosalinda",
+                      "last_name": "Swift",
+                      "emails": [],
+                      "username": "rosalinda@foo-corp.com",
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "custom_attributes": {},
+                      "raw_attributes": {}
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let _groups_mock = server
+            .mock("GET", "/directory_groups")
+            .match_query(Matcher::UrlEncoded(
+                "directory".to_string(),
+                "directory_01ECAZ4NV9QMV47GW873HDCX74".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                      "idp_id": "02grqrue4294w24",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "name": "Engineering",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "raw_attributes": {}
+                    },
+                    {
+                      "id": "directory_group_01E64QTDNS0EGJ0FMCVY9BWGZT",
+                      "idp_id": "02grqrue4294w25",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "name": "Admins",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "raw_attributes": {}
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let engineering_mock = server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::UrlEncoded(
+                "group".to_string(),
+                ENGINEERING.to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                      "idp_id": "2836",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "first_name": "Marcelina",
+                      "last_name": "Davis",
+                      "emails": [],
+                      "username": "marcelina@foo-corp.com",
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "custom_attributes": {},
+                      "raw_attributes": {}
+                    },
+                    {
+                      "id": "directory_user_01E1JJHG10ANRA2V6PAX3GD7TE",
+                      "idp_id": "8953",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "first_name": "Rosalinda",
+                      "last_name": "Swift",
+                      "emails": [],
+                      "username": "rosalinda@foo-corp.com",
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "custom_attributes": {},
+                      "raw_attributes": {}
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let admins_mock = server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::UrlEncoded("group".to_string(), ADMINS.to_string()))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                      "idp_id": "2836",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "first_name": "Marcelina",
+                      "last_name": "Davis",
+                      "emails": [],
+                      "username": "marcelina@foo-corp.com",
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "custom_attributes": {},
+                      "raw_attributes": {}
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let graph = DirectoryGraph::build(
+            &workos,
+            &DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+            2,
+        )
+        .await
+        .unwrap();
+
+        engineering_mock.assert_async().await;
+        admins_mock.assert_async().await;
+        assert_eq!(
+            owned(graph.members(&DirectoryGroupId::from(ENGINEERING))),
+            ids(&[MARCELINA, ROSALINDA])
+        );
+        assert_eq!(
+            owned(graph.members(&DirectoryGroupId::from(ADMINS))),
+            ids(&[MARCELINA])
+        );
+    }
+
+    #[tokio::test]
+    async fn it_applies_group_membership_webhook_events() {
+        let mut graph = build_graph().await;
+
+        let user = graph
+            .user(&DirectoryUserId::from(ROSALINDA))
+            .cloned()
+            .unwrap();
+        let group = graph
+            .group(&DirectoryGroupId::from(ADMINS))
+            .cloned()
+            .unwrap();
+
+        let added = WebhookEvent::DirectoryUserAddedToGroup(DirectoryUserAddedToGroupWebhook {
+            directory_id: DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+            user: user.clone(),
+            group: group.clone(),
+        });
+        assert!(graph.apply_event(&added));
+        assert!(!graph.apply_event(&added));
+        assert_eq!(
+            owned(graph.members(&DirectoryGroupId::from(ADMINS))),
+            ids(&[MARCELINA, ROSALINDA])
+        );
+        assert!(graph
+            .user(&DirectoryUserId::from(ROSALINDA))
+            .unwrap()
+            .groups
+            .iter()
+            .any(|group| group.id == DirectoryGroupId::from(ADMINS)));
+
+        let removed =
+            WebhookEvent::DirectoryUserRemovedFromGroup(DirectoryUserRemovedFromGroupWebhook {
+                directory_id: DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+                user: user.clone(),
+                group: group.clone(),
+            });
+        assert!(graph.apply_event(&removed));
+        assert_eq!(
+            owned(graph.members(&DirectoryGroupId::from(ADMINS))),
+            ids(&[MARCELINA])
+        );
+        assert!(graph
+            .groups_of(&DirectoryUserId::from(ROSALINDA))
+            .iter()
+            .all(|group_id| **group_id == DirectoryGroupId::from(ENGINEERING)));
+        assert!(graph
+            .user(&DirectoryUserId::from(ROSALINDA))
+            .unwrap()
+            .groups
+            .iter()
+            .all(|group| group.id != DirectoryGroupId::from(ADMINS)));
+
+        let other_directory =
+            WebhookEvent::DirectoryUserAddedToGroup(DirectoryUserAddedToGroupWebhook {
+                directory_id: DirectoryId::from("directory_01E1X194NTJ3PYMAY79DYV0F0P"),
+                user,
+                group,
+            });
+        assert!(!graph.apply_event(&other_directory));
+    }
+}
@@ -0,0 +1,712 @@
+mod store;
+
+pub use store::*;
+
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::directory_sync::{
+    DirectoryGroup, DirectoryGroupsFilter, DirectoryId, DirectoryUser, DirectoryUsersFilter,
+    ListDirectoryGroups, ListDirectoryGroupsParams, ListDirectoryUsers, ListDirectoryUsersParams,
+};
+use crate::webhooks::WebhookEvent;
+use crate::{paginate_all, PaginationParams, WorkOs, WorkOsError, WorkOsResult};
+
+/// An error returned from a [`Mirror`].
+#[derive(Debug, Error)]
+pub enum MirrorError<E> {
+    /// The directory users or directory groups could not be listed.
+    #[error("failed to list the directory")]
+    List,
+
+    /// The store returned an error.
+    #[error("mirror store failed")]
+    Store(E),
+}
+
+impl<E> From<MirrorError<E>> for WorkOsError<MirrorError<E>> {
+    fn from(err: MirrorError<E>) -> Self {
+        Self::Operation(err)
+    }
+}
+
+/// Mirrors directories into a local [`MirrorStore`].
+///
+/// Call [`reconcile`](Mirror::reconcile) once for the initial full sync, apply `dsync.*`
+/// webhooks with [`apply_event`](Mirror::apply_event) as they arrive, and re-reconcile
+/// periodically, e.g., with [`run`](Mirror::run), to correct any drift from missed or
+/// out-of-order webhooks.
+///
+/// # Examples
+///
+/// ```
+/// # use workos::WorkOsResult;
+/// # use workos::directory_sync::*;
+/// # use workos::webhooks::WebhookEvent;
+/// use workos::{ApiKey, WorkOs};
+///
+/// # async fn run(
+/// #     event: WebhookEvent,
+/// # ) -> WorkOsResult<(), MirrorError<std::convert::Infallible>> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+/// let mirror = Mirror::new(&workos, InMemoryMirrorStore::default());
+///
+/// let directory_id = DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74");
+/// mirror.reconcile(&directory_id).await?;
+///
+/// // When a webhook is received:
+/// mirror.apply_event(&event).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Mirror<'a, S> {
+    workos: &'a WorkOs,
+    store: S,
+}
+
+impl<'a, S: MirrorStore> Mirror<'a, S> {
+    /// Returns a new [`Mirror`] instance that persists to the provided store.
+    pub fn new(workos: &'a WorkOs, store: S) -> Self {
+        Self { workos, store }
+    }
+
+    /// Returns the store the mirror persists to.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Applies a `dsync.user.*` or `dsync.group.*` webhook event to the store.
+    ///
+    /// A created or updated directory user also updates its memberships to match its
+    /// [`groups`](DirectoryUser::groups). Other events are ignored.
+    pub async fn apply_event(
+        &self,
+        event: &WebhookEvent,
+    ) -> WorkOsResult<(), MirrorError<S::Error>> {
+        let (directory_id, change) = match event {
+            WebhookEvent::DirectoryUserCreated(webhook) => {
+                return self.apply_user_upserted(&webhook.0).await;
+            }
+            WebhookEvent::DirectoryUserUpdated(webhook) => {
+                return self.apply_user_upserted(&webhook.0.directory_user).await;
+            }
+            WebhookEvent::DirectoryUserDeleted(webhook) => (
+                &webhook.0.directory_id,
+                MirrorChange::UserDeleted(webhook.0.id.clone()),
+            ),
+            WebhookEvent::DirectoryGroupCreated(webhook) => (
+                &webhook.0.directory_id,
+                MirrorChange::GroupUpserted(webhook.0.clone()),
+            ),
+            WebhookEvent::DirectoryGroupUpdated(webhook) => (
+                &webhook.0.directory_group.directory_id,
+                MirrorChange::GroupUpserted(webhook.0.directory_group.clone()),
+            ),
+            WebhookEvent::DirectoryGroupDeleted(webhook) => (
+                &webhook.0.directory_id,
+                MirrorChange::GroupDeleted(webhook.0.id.clone()),
+            ),
+            WebhookEvent::DirectoryUserAddedToGroup(webhook) => (
+                &webhook.directory_id,
+                MirrorChange::MembershipAdded {
+                    group_id: webhook.group.id.clone(),
+                    user_id: webhook.user.id.clone(),
+                },
+            ),
+            WebhookEvent::DirectoryUserRemovedFromGroup(webhook) => (
+                &webhook.directory_id,
+                MirrorChange::MembershipRemoved {
+                    group_id: webhook.group.id.clone(),
+                    user_id: webhook.user.id.clone(),
+                },
+            ),
+            _ => return Ok(()),
+        };
+
+        self.store
+            .apply(directory_id, &[change])
+            .await
+            .map_err(|err| WorkOsError::Operation(MirrorError::Store(err)))
+    }
+
+    async fn apply_user_upserted(
+        &self,
+        user: &DirectoryUser,
+    ) -> WorkOsResult<(), MirrorError<S::Error>> {
+        let local = self
+            .store
+            .load(&user.directory_id)
+            .await
+            .map_err(|err| WorkOsError::Operation(MirrorError::Store(err)))?;
+
+        let stored_group_ids = local
+            .memberships
+            .iter()
+            .filter(|(_, user_id)| *user_id == user.id)
+            .map(|(group_id, _)| group_id)
+            .collect::<BTreeSet<_>>();
+        let group_ids = user
+            .groups
+            .iter()
+            .map(|group| &group.id)
+            .collect::<BTreeSet<_>>();
+
+        let mut changes = vec![MirrorChange::UserUpserted(Box::new(user.clone()))];
+        for group_id in group_ids.difference(&stored_group_ids) {
+            changes.push(MirrorChange::MembershipAdded {
+                group_id: (*group_id).clone(),
+                user_id: user.id.clone(),
+            });
+        }
+        for group_id in stored_group_ids.difference(&group_ids) {
+            changes.push(MirrorChange::MembershipRemoved {
+                group_id: (*group_id).clone(),
+                user_id: user.id.clone(),
+            });
+        }
+
+        self.store
+            .apply(&user.directory_id, &changes)
+            .await
+            .map_err(|err| WorkOsError::Operation(MirrorError::Store(err)))
+    }
+
+    /// Lists all directory users and directory groups of a directory, and applies the
+    /// changes needed to bring the store in line with them.
+    ///
+    /// Memberships are taken from the [`groups`](DirectoryUser::groups) of the listed
+    /// users, so a reconciliation only makes two paginated listings.
+    ///
+    /// Returns the applied changes, i.e., the drift that was corrected. For a directory
+    /// that was never synced, this is a full sync.
+    pub async fn reconcile(
+        &self,
+        directory_id: &DirectoryId,
+    ) -> WorkOsResult<Vec<MirrorChange>, MirrorError<S::Error>> {
+        let users = self
+            .list_all_users(directory_id)
+            .await
+            .map_err(|err| err.map_operation(|()| MirrorError::List))?;
+        let groups = self
+            .list_all_groups(directory_id)
+            .await
+            .map_err(|err| err.map_operation(|()| MirrorError::List))?;
+
+        let memberships = users
+            .iter()
+            .flat_map(|user| {
+                user.groups
+                    .iter()
+                    .map(|group| (group.id.clone(), user.id.clone()))
+            })
+            .collect::<BTreeSet<_>>();
+
+        let local = self
+            .store
+            .load(directory_id)
+            .await
+            .map_err(|err| WorkOsError::Operation(MirrorError::Store(err)))?;
+
+        let mut changes = Vec::new();
+
+        for group in &groups {
+            if local.groups.get(&group.id) != Some(group) {
+                changes.push(MirrorChange::GroupUpserted(group.clone()));
+            }
+        }
+        for user in &users {
+            if local.users.get(&user.id) != Some(user) {
+                changes.push(MirrorChange::UserUpserted(Box::new(user.clone())));
+            }
+        }
+        for (group_id, user_id) in memberships.difference(&local.memberships) {
+            changes.push(MirrorChange::MembershipAdded {
+                group_id: group_id.clone(),
+                user_id: user_id.clone(),
+            });
+        }
+        for (group_id, user_id) in local.memberships.difference(&memberships) {
+            changes.push(MirrorChange::MembershipRemoved {
+                group_id: group_id.clone(),
+                user_id: user_id.clone(),
+            });
+        }
+        let user_ids = users.iter().map(|user| &user.id).collect::<BTreeSet<_>>();
+        for user_id in local.users.keys() {
+            if !user_ids.contains(user_id) {
+                changes.push(MirrorChange::UserDeleted(user_id.clone()));
+            }
+        }
+        let group_ids = groups
+            .iter()
+            .map(|group| &group.id)
+            .collect::<BTreeSet<_>>();
+        for group_id in local.groups.keys() {
+            if !group_ids.contains(group_id) {
+                changes.push(MirrorChange::GroupDeleted(group_id.clone()));
+            }
+        }
+
+        if !changes.is_empty() {
+            self.store
+                .apply(directory_id, &changes)
+                .await
+                .map_err(|err| WorkOsError::Operation(MirrorError::Store(err)))?;
+        }
+
+        Ok(changes)
+    }
+
+    /// Reconciles the directories every `interval`, until a reconciliation fails, and
+    /// calls `on_drift` with the changes whenever drift was corrected.
+    ///
+    /// See [`reconcile`](Mirror::reconcile).
+    pub async fn run<F>(
+        &self,
+        directory_ids: &[DirectoryId],
+        interval: Duration,
+        mut on_drift: F,
+    ) -> WorkOsResult<(), MirrorError<S::Error>>
+    where
+        F: FnMut(&DirectoryId, &[MirrorChange]),
+    {
+        loop {
+            for directory_id in directory_ids {
+                let changes = self.reconcile(directory_id).await?;
+                if !changes.is_empty() {
+                    on_drift(directory_id, &changes);
+                }
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    async fn list_all_users(
+        &self,
+        directory_id: &DirectoryId,
+    ) -> WorkOsResult<Vec<DirectoryUser>, ()> {
+        paginate_all(|after| async move {
+            self.workos
+                .directory_sync()
+                .list_directory_users(&ListDirectoryUsersParams {
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        ..Default::default()
+                    },
+                    filter: DirectoryUsersFilter::Directory {
+                        directory: directory_id,
+                    },
+                })
+                .await
+        })
+        .await
+    }
+
+    async fn list_all_groups(
+        &self,
+        directory_id: &DirectoryId,
+    ) -> WorkOsResult<Vec<DirectoryGroup>, ()> {
+        paginate_all(|after| async move {
+            self.workos
+                .directory_sync()
+                .list_directory_groups(&ListDirectoryGroupsParams {
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        ..Default::default()
+                    },
+                    filter: DirectoryGroupsFilter::Directory {
+                        directory: directory_id,
+                    },
+                })
+                .await
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::directory_sync::{DirectoryGroupId, DirectoryUserId};
+    use crate::ApiKey;
+
+    #[tokio::test]
+    async fn it_reconciles_a_directory_and_returns_the_corrected_drift() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let users_mock = server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::UrlEncoded(
+                "directory".to_string(),
+                "directory_01ECAZ4NV9QMV47GW873HDCX74".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                      "idp_id": "2836",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+                      "first_name": "Marcelina",
+                      "last_name": "Davis",
+                      "emails": [
+                        {
+                          "primary": true,
+                          "type": "work",
+                          "value": "marcelina@foo-corp.com"
+                        }
+                      ],
+                      "username": "marcelina@foo-corp.com",
+                      "groups": [
+                        {
+                          "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                          "name": "Developers",
+                          "created_at": "2021-06-25T19:07:33.155Z",
+                          "updated_at": "2021-06-25T19:07:33.155Z",
+                          "raw_attributes": {}
+                        }
+                      ],
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "custom_attributes": {},
+                      "raw_attributes": {}
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .expect(3)
+            .create_async()
+            .await;
+
+        let groups_mock = server
+            .mock("GET", "/directory_groups")
+            .match_query(Matcher::UrlEncoded(
+                "directory".to_string(),
+                "directory_01ECAZ4NV9QMV47GW873HDCX74".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                      "idp_id": "02grqrue4294w24",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+                      "name": "Developers",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "raw_attributes": {}
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .expect(3)
+            .create_async()
+            .await;
+
+        let directory_id = DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74");
+        let user_id = DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SJ");
+        let group_id = DirectoryGroupId::from("directory_group_01E1JJS84MFPPQ3G655FHTKX6Z");
+        let mirror = Mirror::new(&workos, InMemoryMirrorStore::default());
+
+        let changes = mirror.reconcile(&directory_id).await.unwrap();
+        assert_eq!(changes.len(), 3);
+        assert_matches!(&changes[0], MirrorChange::GroupUpserted(group) if group.id == group_id);
+        assert_matches!(&changes[1], MirrorChange::UserUpserted(user) if user.id == user_id);
+        assert_eq!(
+            changes[2],
+            MirrorChange::MembershipAdded {
+                group_id: group_id.clone(),
+                user_id: user_id.clone(),
+            }
+        );
+
+        let stale_user = serde_json::from_value(json!({
+          "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SK",
+          "idp_id": "2836",
+          "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+          "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+          "first_name": "Marcelina",
+          "last_name": "Davis",
+          "emails": [
+            {
+              "primary": true,
+              "type": "work",
+              "value": "marcelina@foo-corp.com"
+            }
+          ],
+          "username": "marcelina@foo-corp.com",
+          "groups": [],
+          "state": "active",
+          "created_at": "2021-06-25T19:07:33.155Z",
+          "updated_at": "2021-06-25T19:07:33.155Z",
+          "custom_attributes": {},
+          "raw_attributes": {}
+        }))
+        .unwrap();
+        mirror
+            .store()
+            .apply(
+                &directory_id,
+                &[
+                    MirrorChange::UserUpserted(Box::new(stale_user)),
+                    MirrorChange::MembershipRemoved {
+                        group_id: group_id.clone(),
+                        user_id: user_id.clone(),
+                    },
+                ],
+            )
+            .await
+            .unwrap();
+
+        let changes = mirror.reconcile(&directory_id).await.unwrap();
+        assert_eq!(
+            changes,
+            vec![
+                MirrorChange::MembershipAdded { group_id, user_id },
+                MirrorChange::UserDeleted(DirectoryUserId::from(
+                    "directory_user_01E1JG7J09H96KYP8HM9B0G5SK"
+                )),
+            ]
+        );
+
+        assert_eq!(mirror.reconcile(&directory_id).await.unwrap(), vec![]);
+        users_mock.assert_async().await;
+        groups_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_applies_webhook_events() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+        let mirror = Mirror::new(&workos, InMemoryMirrorStore::default());
+
+        let events: Vec<WebhookEvent> = serde_json::from_value(json!([
+          {
+            "event": "dsync.user.created",
+            "data": {
+              "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+              "idp_id": "2836",
+              "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+              "first_name": "Marcelina",
+              "last_name": "Davis",
+              "emails": [
+                {
+                  "primary": true,
+                  "type": "work",
+                  "value": "marcelina@foo-corp.com"
+                }
+              ],
+              "username": "marcelina@foo-corp.com",
+              "groups": [],
+              "state": "active",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+              "custom_attributes": {},
+              "raw_attributes": {}
+            }
+          },
+          {
+            "event": "dsync.group.created",
+            "data": {
+              "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+              "idp_id": "02grqrue4294w24",
+              "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+              "name": "Developers",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+              "raw_attributes": {}
+            }
+          },
+          {
+            "event": "dsync.group.user_added",
+            "data": {
+              "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "user": {
+                "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                "idp_id": "2836",
+                "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+                "first_name": "Marcelina",
+                "last_name": "Davis",
+                "emails": [
+                  {
+                    "primary": true,
+                    "type": "work",
+                    "value": "marcelina@foo-corp.com"
+                  }
+                ],
+                "username": "marcelina@foo-corp.com",
+                "groups": [],
+                "state": "active",
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z",
+                "custom_attributes": {},
+                "raw_attributes": {}
+              },
+              "group": {
+                "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                "idp_id": "02grqrue4294w24",
+                "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+                "name": "Developers",
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z",
+                "raw_attributes": {}
+              }
+            }
+          },
+          {
+            "event": "dsync.group.deleted",
+            "data": {
+              "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+              "idp_id": "02grqrue4294w24",
+              "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+              "name": "Developers",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+              "raw_attributes": {}
+            }
+          }
+        ]))
+        .unwrap();
+
+        for event in &events[..3] {
+            mirror.apply_event(event).await.unwrap();
+        }
+
+        let state = mirror
+            .store()
+            .load(&DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"))
+            .await
+            .unwrap();
+        assert_eq!(state.users.len(), 1);
+        assert_eq!(state.groups.len(), 1);
+        assert_eq!(state.memberships.len(), 1);
+
+        mirror.apply_event(&events[3]).await.unwrap();
+
+        let state = mirror
+            .store()
+            .load(&DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"))
+            .await
+            .unwrap();
+        assert_eq!(state.users.len(), 1);
+        assert!(state.groups.is_empty());
+        assert!(state.memberships.is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_syncs_the_memberships_of_an_upserted_user_with_its_groups() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+        let mirror = Mirror::new(&workos, InMemoryMirrorStore::default());
+
+        let events: Vec<WebhookEvent> = serde_json::from_value(json!([
+          {
+            "event": "dsync.user.created",
+            "data": {
+              "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+              "idp_id": "2836",
+              "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+              "first_name": "Marcelina",
+              "last_name": "Davis",
+              "emails": [
+                {
+                  "primary": true,
+                  "type": "work",
+                  "value": "marcelina@foo-corp.com"
+                }
+              ],
+              "username": "marcelina@foo-corp.com",
+              "groups": [
+                {
+                  "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                  "name": "Developers",
+                  "created_at": "2021-06-25T19:07:33.155Z",
+                  "updated_at": "2021-06-25T19:07:33.155Z",
+                  "raw_attributes": {}
+                }
+              ],
+              "state": "active",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+              "custom_attributes": {},
+              "raw_attributes": {}
+            }
+          },
+          {
+            "event": "dsync.user.updated",
+            "data": {
+              "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+              "idp_id": "2836",
+              "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+              "first_name": "Marcelina",
+              "last_name": "Davis",
+              "emails": [
+                {
+                  "primary": true,
+                  "type": "work",
+                  "value": "marcelina@foo-corp.com"
+                }
+              ],
+              "username": "marcelina@foo-corp.com",
+              "groups": [],
+              "state": "active",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+              "custom_attributes": {},
+              "raw_attributes": {},
+              "previous_attributes": {}
+            }
+          }
+        ]))
+        .unwrap();
+
+        let directory_id = DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74");
+        let membership = (
+            DirectoryGroupId::from("directory_group_01E1JJS84MFPPQ3G655FHTKX6Z"),
+            DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SJ"),
+        );
+
+        mirror.apply_event(&events[0]).await.unwrap();
+
+        let state = mirror.store().load(&directory_id).await.unwrap();
+        assert_eq!(state.memberships, BTreeSet::from([membership]));
+
+        mirror.apply_event(&events[1]).await.unwrap();
+
+        let state = mirror.store().load(&directory_id).await.unwrap();
+        assert_eq!(state.users.len(), 1);
+        assert!(state.memberships.is_empty());
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::convert::Infallible;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::directory_sync::{
+    DirectoryGroup, DirectoryGroupId, DirectoryId, DirectoryUser, DirectoryUserId,
+};
+
+/// A change to the local copy of a directory, applied by a [`Mirror`](crate::directory_sync::Mirror).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MirrorChange {
+    /// A directory user was created or updated.
+    UserUpserted(Box<DirectoryUser>),
+
+    /// A directory user was deleted.
+    UserDeleted(DirectoryUserId),
+
+    /// A directory group was created or updated.
+    GroupUpserted(DirectoryGroup),
+
+    /// A directory group was deleted.
+    GroupDeleted(DirectoryGroupId),
+
+    /// A directory user was added to a directory group.
+    MembershipAdded {
+        /// The ID of the directory group.
+        group_id: DirectoryGroupId,
+
+        /// The ID of the directory user.
+        user_id: DirectoryUserId,
+    },
+
+    /// A directory user was removed from a directory group.
+    MembershipRemoved {
+        /// The ID of the directory group.
+        group_id: DirectoryGroupId,
+
+        /// The ID of the directory user.
+        user_id: DirectoryUserId,
+    },
+}
+
+/// The local copy of a directory, as loaded from a [`MirrorStore`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MirrorState {
+    /// The directory users, by ID.
+    pub users: BTreeMap<DirectoryUserId, DirectoryUser>,
+
+    /// The directory groups, by ID.
+    pub groups: BTreeMap<DirectoryGroupId, DirectoryGroup>,
+
+    /// The memberships of directory users in directory groups.
+    pub memberships: BTreeSet<(DirectoryGroupId, DirectoryUserId)>,
+}
+
+impl MirrorState {
+    /// Applies a change to the state.
+    ///
+    /// Deleting a directory user or group also removes its memberships.
+    pub fn apply(&mut self, change: &MirrorChange) {
+        match change {
+            MirrorChange::UserUpserted(user) => {
+                self.users.insert(user.id.clone(), (**user).clone());
+            }
+            MirrorChange::UserDeleted(user_id) => {
+                self.users.remove(user_id);
+                self.memberships
+                    .retain(|(_, member_id)| member_id != user_id);
+            }
+            MirrorChange::GroupUpserted(group) => {
+                self.groups.insert(group.id.clone(), group.clone());
+            }
+            MirrorChange::GroupDeleted(group_id) => {
+                self.groups.remove(group_id);
+                self.memberships
+                    .retain(|(member_group_id, _)| member_group_id != group_id);
+            }
+            MirrorChange::MembershipAdded { group_id, user_id } => {
+                self.memberships.insert((group_id.clone(), user_id.clone()));
+            }
+            MirrorChange::MembershipRemoved { group_id, user_id } => {
+                self.memberships
+                    .remove(&(group_id.clone(), user_id.clone()));
+            }
+        }
+    }
+}
+
+/// A store that persists the local copy of directories for a
+/// [`Mirror`](crate::directory_sync::Mirror).
+#[async_trait]
+pub trait MirrorStore: Send + Sync {
+    /// The error returned from the store.
+    type Error: Send;
+
+    /// Loads the local copy of a directory.
+    ///
+    /// Returns an empty [`MirrorState`] for a directory that was never synced.
+    async fn load(&self, directory_id: &DirectoryId) -> Result<MirrorState, Self::Error>;
+
+    /// Applies changes to the local copy of a directory, in order.
+    async fn apply(
+        &self,
+        directory_id: &DirectoryId,
+        changes: &[MirrorChange],
+    ) -> Result<(), Self::Error>;
+}
+
+/// A [`MirrorStore`] that keeps directories in memory.
+#[derive(Debug, Default)]
+pub struct InMemoryMirrorStore {
+    directories: Mutex<HashMap<DirectoryId, MirrorState>>,
+}
+
+#[async_trait]
+impl MirrorStore for InMemoryMirrorStore {
+    type Error = Infallible;
+
+    async fn load(&self, directory_id: &DirectoryId) -> Result<MirrorState, Self::Error> {
+        let directories = self
+            .directories
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+
+        Ok(directories.get(directory_id).cloned().unwrap_or_default())
+    }
+
+    async fn apply(
+        &self,
+        directory_id: &DirectoryId,
+        changes: &[MirrorChange],
+    ) -> Result<(), Self::Error> {
+        let mut directories = self
+            .directories
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+
+        let state = directories.entry(directory_id.clone()).or_default();
+        for change in changes {
+            state.apply(change);
+        }
+
+        Ok(())
+    }
+}
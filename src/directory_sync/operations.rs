@@ -1,4 +1,5 @@
 mod delete_directory;
+mod export_directory;
 mod get_directory;
 mod get_directory_group;
 mod get_directory_user;
@@ -7,6 +8,7 @@ mod list_directory_groups;
 mod list_directory_users;
 
 pub use delete_directory::*;
+pub use export_directory::*;
 pub use get_directory::*;
 pub use get_directory_group::*;
 pub use get_directory_user::*;
@@ -0,0 +1,930 @@
+use std::collections::BTreeMap;
+use std::pin::pin;
+
+use async_trait::async_trait;
+use futures_util::stream::TryStreamExt;
+use serde::Serialize;
+use thiserror::Error;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::directory_sync::{
+    DirectoryGroup, DirectoryGroupId, DirectoryGroupsFilter, DirectoryId, DirectorySync,
+    DirectoryUser, DirectoryUserId, DirectoryUserState, DirectoryUsersFilter, ListDirectoryGroups,
+    ListDirectoryGroupsParams, ListDirectoryUsers, ListDirectoryUsersParams,
+};
+use crate::{paginate, KnownOrUnknown, PaginationParams, Timestamp, WorkOsError, WorkOsResult};
+
+/// The format of an export written by [`ExportDirectory`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportFormat {
+    /// A SCIM 2.0 `ListResponse` with a `User` resource for every directory user,
+    /// followed by a `Group` resource for every directory group.
+    ///
+    /// Users list the groups they are a member of, and groups list their members. A user
+    /// without a username or email address has the ID assigned by the Directory Provider
+    /// as their `userName`.
+    ScimJson,
+
+    /// A CSV file with a row for every directory user, followed by a row for every
+    /// directory group.
+    ///
+    /// The columns are `type,id,idp_id,name,email,first_name,last_name,state,groups`,
+    /// where `name` is the username of a user or the name of a group, and `groups` are
+    /// the IDs of the groups a user is a member of, separated by `;`.
+    ///
+    /// Values starting with `=`, `+`, `-`, `@`, a tab or a carriage return are prefixed
+    /// with `'`, so that spreadsheet applications do not evaluate them as formulas. Values
+    /// containing a comma, a quote or a line break are quoted.
+    Csv,
+}
+
+/// The summary returned from [`ExportDirectory`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportDirectorySummary {
+    /// The number of directory users that were exported.
+    pub users: usize,
+
+    /// The number of directory groups that were exported.
+    pub groups: usize,
+}
+
+/// An error returned from [`ExportDirectory`].
+#[derive(Debug, Error)]
+pub enum ExportDirectoryError {
+    /// The export could not be written.
+    #[error("failed to write export")]
+    Io(#[from] std::io::Error),
+
+    /// The directory users or directory groups could not be listed.
+    #[error("failed to list the directory")]
+    List,
+}
+
+impl From<ExportDirectoryError> for WorkOsError<ExportDirectoryError> {
+    fn from(err: ExportDirectoryError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+/// Exports the users and groups of a directory, e.g., to show an organization what was
+/// received from their Directory Provider.
+#[async_trait]
+pub trait ExportDirectory {
+    /// Writes all [`DirectoryUser`]s and [`DirectoryGroup`]s of a directory to `writer`
+    /// in the provided format.
+    ///
+    /// Users and groups are written page by page as they are listed; only the group
+    /// memberships are kept in memory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use workos::WorkOsResult;
+    /// # use workos::directory_sync::*;
+    /// use workos::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), ExportDirectoryError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let mut output = Vec::new();
+    /// let summary = workos
+    ///     .directory_sync()
+    ///     .export_directory(
+    ///         &DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+    ///         ExportFormat::ScimJson,
+    ///         &mut output,
+    ///     )
+    ///     .await?;
+    ///
+    /// println!("exported {} users and {} groups", summary.users, summary.groups);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn export_directory<W>(
+        &self,
+        directory_id: &DirectoryId,
+        format: ExportFormat,
+        writer: &mut W,
+    ) -> WorkOsResult<ExportDirectorySummary, ExportDirectoryError>
+    where
+        W: AsyncWrite + Unpin + Send;
+}
+
+#[async_trait]
+impl ExportDirectory for DirectorySync<'_> {
+    async fn export_directory<W>(
+        &self,
+        directory_id: &DirectoryId,
+        format: ExportFormat,
+        writer: &mut W,
+    ) -> WorkOsResult<ExportDirectorySummary, ExportDirectoryError>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        let mut summary = ExportDirectorySummary::default();
+        let mut members: BTreeMap<DirectoryGroupId, Vec<ScimReference>> = BTreeMap::new();
+
+        let header = match format {
+            ExportFormat::ScimJson => SCIM_LIST_RESPONSE_START,
+            ExportFormat::Csv => CSV_HEADER,
+        };
+        write(writer, header.as_bytes()).await?;
+
+        let mut users = pin!(paginate(|after| async move {
+            self.list_directory_users(&ListDirectoryUsersParams {
+                pagination: PaginationParams {
+                    after: after.as_deref(),
+                    ..Default::default()
+                },
+                filter: DirectoryUsersFilter::Directory {
+                    directory: directory_id,
+                },
+            })
+            .await
+        }));
+        while let Some(page) = users
+            .try_next()
+            .await
+            .map_err(|err| err.map_operation(|()| ExportDirectoryError::List))?
+        {
+            for directory_user in &page {
+                for group in &directory_user.groups {
+                    members
+                        .entry(group.id.clone())
+                        .or_default()
+                        .push(ScimReference {
+                            value: directory_user.id.to_string(),
+                            display: display_name(directory_user).map(str::to_string),
+                        });
+                }
+
+                let record = match format {
+                    ExportFormat::ScimJson => scim_record(
+                        summary.users + summary.groups,
+                        &ScimUserResource::from(directory_user),
+                    )?,
+                    ExportFormat::Csv => csv_user_record(directory_user),
+                };
+                write(writer, &record).await?;
+                summary.users += 1;
+            }
+        }
+
+        let mut groups = pin!(paginate(|after| async move {
+            self.list_directory_groups(&ListDirectoryGroupsParams {
+                pagination: PaginationParams {
+                    after: after.as_deref(),
+                    ..Default::default()
+                },
+                filter: DirectoryGroupsFilter::Directory {
+                    directory: directory_id,
+                },
+            })
+            .await
+        }));
+        while let Some(page) = groups
+            .try_next()
+            .await
+            .map_err(|err| err.map_operation(|()| ExportDirectoryError::List))?
+        {
+            for group in &page {
+                let record = match format {
+                    ExportFormat::ScimJson => scim_record(
+                        summary.users + summary.groups,
+                        &ScimGroupResource {
+                            members: members.remove(&group.id).unwrap_or_default(),
+                            ..ScimGroupResource::from(group)
+                        },
+                    )?,
+                    ExportFormat::Csv => csv_group_record(group),
+                };
+                write(writer, &record).await?;
+                summary.groups += 1;
+            }
+        }
+
+        if format == ExportFormat::ScimJson {
+            let footer = format!(
+                "],\"totalResults\":{total},\"startIndex\":1,\"itemsPerPage\":{total}}}\n",
+                total = summary.users + summary.groups
+            );
+            write(writer, footer.as_bytes()).await?;
+        }
+        writer.flush().await.map_err(ExportDirectoryError::from)?;
+
+        Ok(summary)
+    }
+}
+
+const SCIM_LIST_RESPONSE_START: &str =
+    "{\"schemas\":[\"urn:ietf:params:scim:api:messages:2.0:ListResponse\"],\"Resources\":[";
+
+const CSV_HEADER: &str = "type,id,idp_id,name,email,first_name,last_name,state,groups\r\n";
+
+async fn write<W>(writer: &mut W, bytes: &[u8]) -> Result<(), ExportDirectoryError>
+where
+    W: AsyncWrite + Unpin + Send,
+{
+    writer
+        .write_all(bytes)
+        .await
+        .map_err(ExportDirectoryError::from)
+}
+
+fn display_name<TCustomAttributes>(
+    directory_user: &DirectoryUser<TCustomAttributes>,
+) -> Option<&str> {
+    directory_user
+        .username
+        .as_deref()
+        .or_else(|| directory_user.primary_email())
+}
+
+fn scim_record<T: Serialize>(index: usize, resource: &T) -> Result<Vec<u8>, ExportDirectoryError> {
+    let mut record = if index == 0 { Vec::new() } else { vec![b','] };
+    serde_json::to_writer(&mut record, resource).map_err(std::io::Error::from)?;
+
+    Ok(record)
+}
+
+fn csv_user_record<TCustomAttributes>(
+    directory_user: &DirectoryUser<TCustomAttributes>,
+) -> Vec<u8> {
+    let state = match &directory_user.state {
+        KnownOrUnknown::Known(DirectoryUserState::Active) => "active",
+        KnownOrUnknown::Known(DirectoryUserState::Inactive) => "inactive",
+        KnownOrUnknown::Known(DirectoryUserState::Suspended) => "suspended",
+        KnownOrUnknown::Unknown(state) => state,
+    };
+    let groups = directory_user
+        .groups
+        .iter()
+        .map(|group| group.id.to_string())
+        .collect::<Vec<_>>()
+        .join(";");
+
+    csv_record(&[
+        "user",
+        &directory_user.id.to_string(),
+        &directory_user.idp_id,
+        directory_user.username.as_deref().unwrap_or_default(),
+        directory_user.primary_email().unwrap_or_default(),
+        directory_user.first_name.as_deref().unwrap_or_default(),
+        directory_user.last_name.as_deref().unwrap_or_default(),
+        state,
+        &groups,
+    ])
+}
+
+fn csv_group_record(group: &DirectoryGroup) -> Vec<u8> {
+    csv_record(&[
+        "group",
+        &group.id.to_string(),
+        &group.idp_id,
+        &group.name,
+        "",
+        "",
+        "",
+        "",
+        "",
+    ])
+}
+
+fn csv_record(fields: &[&str]) -> Vec<u8> {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let field = if field.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+                format!("'{field}")
+            } else {
+                field.to_string()
+            };
+
+            if field.contains([',', '"', '\r', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect::<Vec<_>>();
+
+    format!("{}\r\n", fields.join(",")).into_bytes()
+}
+
+#[derive(Serialize)]
+struct ScimReference {
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScimMeta<'a> {
+    resource_type: &'static str,
+    created: &'a Timestamp,
+    last_modified: &'a Timestamp,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScimUserResource<'a> {
+    schemas: [&'static str; 1],
+    id: &'a DirectoryUserId,
+    external_id: &'a str,
+    user_name: &'a str,
+    name: ScimUserName<'a>,
+    emails: Vec<ScimUserEmail<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+    active: bool,
+    groups: Vec<ScimReference>,
+    meta: ScimMeta<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScimUserName<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    given_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    family_name: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct ScimUserEmail<'a> {
+    value: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#type: Option<&'a str>,
+    primary: bool,
+}
+
+impl<'a, TCustomAttributes> From<&'a DirectoryUser<TCustomAttributes>> for ScimUserResource<'a> {
+    fn from(directory_user: &'a DirectoryUser<TCustomAttributes>) -> Self {
+        Self {
+            schemas: ["urn:ietf:params:scim:schemas:core:2.0:User"],
+            id: &directory_user.id,
+            external_id: &directory_user.idp_id,
+            user_name: display_name(directory_user).unwrap_or(&directory_user.idp_id),
+            name: ScimUserName {
+                given_name: directory_user.first_name.as_deref(),
+                family_name: directory_user.last_name.as_deref(),
+            },
+            emails: directory_user
+                .emails
+                .iter()
+                .filter_map(|email| {
+                    Some(ScimUserEmail {
+                        value: email.value.as_deref()?,
+                        r#type: email.r#type.as_deref(),
+                        primary: email.is_primary(),
+                    })
+                })
+                .collect(),
+            title: directory_user.job_title.as_deref(),
+            active: directory_user.state == KnownOrUnknown::Known(DirectoryUserState::Active),
+            groups: directory_user
+                .groups
+                .iter()
+                .map(|group| ScimReference {
+                    value: group.id.to_string(),
+                    display: Some(group.name.clone()),
+                })
+                .collect(),
+            meta: ScimMeta {
+                resource_type: "User",
+                created: &directory_user.timestamps.created_at,
+                last_modified: &directory_user.timestamps.updated_at,
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScimGroupResource<'a> {
+    schemas: [&'static str; 1],
+    id: &'a DirectoryGroupId,
+    external_id: &'a str,
+    display_name: &'a str,
+    members: Vec<ScimReference>,
+    meta: ScimMeta<'a>,
+}
+
+impl<'a> From<&'a DirectoryGroup> for ScimGroupResource<'a> {
+    fn from(group: &'a DirectoryGroup) -> Self {
+        Self {
+            schemas: ["urn:ietf:params:scim:schemas:core:2.0:Group"],
+            id: &group.id,
+            external_id: &group.idp_id,
+            display_name: &group.name,
+            members: Vec::new(),
+            meta: ScimMeta {
+                resource_type: "Group",
+                created: &group.timestamps.created_at,
+                last_modified: &group.timestamps.updated_at,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockito::{Matcher, Mock, ServerGuard};
+    use serde::Deserialize;
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::{ApiKey, WorkOs};
+
+    /// SCIM 2.0 resources that reject anything RFC 7643 does not define, so that the export
+    /// is checked against the schema rather than parsed leniently.
+    mod strict {
+        use serde::Deserialize;
+        use serde_json::Value;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[serde(rename_all = "camelCase", deny_unknown_fields)]
+        pub struct ListResponse {
+            pub schemas: Vec<String>,
+            pub total_results: usize,
+            pub start_index: usize,
+            pub items_per_page: usize,
+            #[serde(rename = "Resources")]
+            pub resources: Vec<Value>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[serde(rename_all = "camelCase", deny_unknown_fields)]
+        pub struct User {
+            pub schemas: Vec<String>,
+            pub id: String,
+            pub external_id: Option<String>,
+            pub user_name: String,
+            pub name: Option<Name>,
+            #[serde(default)]
+            pub emails: Vec<Email>,
+            pub title: Option<String>,
+            pub active: Option<bool>,
+            #[serde(default)]
+            pub groups: Vec<Reference>,
+            pub meta: Option<Meta>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[serde(rename_all = "camelCase", deny_unknown_fields)]
+        pub struct Name {
+            pub given_name: Option<String>,
+            pub family_name: Option<String>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[serde(deny_unknown_fields)]
+        pub struct Email {
+            pub value: String,
+            pub r#type: Option<String>,
+            pub primary: Option<bool>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[serde(rename_all = "camelCase", deny_unknown_fields)]
+        pub struct Group {
+            pub schemas: Vec<String>,
+            pub id: String,
+            pub external_id: Option<String>,
+            pub display_name: String,
+            #[serde(default)]
+            pub members: Vec<Reference>,
+            pub meta: Option<Meta>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[serde(deny_unknown_fields)]
+        pub struct Reference {
+            pub value: String,
+            pub display: Option<String>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[serde(rename_all = "camelCase", deny_unknown_fields)]
+        pub struct Meta {
+            pub resource_type: String,
+            pub created: String,
+            pub last_modified: String,
+        }
+    }
+
+    async fn mock_directory(server: &mut ServerGuard) -> Vec<Mock> {
+        let group = json!({
+            "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+            "name": "Developers, Inc.",
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        });
+
+        vec![
+            server
+                .mock("GET", "/directory_users")
+                .match_query(Matcher::UrlEncoded(
+                    "directory".to_string(),
+                    "directory_01ECAZ4NV9QMV47GW873HDCX74".to_string(),
+                ))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "data": [
+                            {
+                                "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                                "idp_id": "2836",
+                                "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                                "first_name": "Marcelina",
+                                "last_name": "Davis",
+                                "job_title": "Software Engineer",
+                                "emails": [{
+                                    "primary": true,
+                                    "type": "work",
+                                    "value": "marcelina@foo-corp.com"
+                                }],
+                                "username": "marcelina@foo-corp.com",
+                                "groups": [group],
+                                "state": "active",
+                                "created_at": "2021-06-25T19:07:33.155Z",
+                                "updated_at": "2021-06-25T19:07:33.155Z",
+                                "custom_attributes": {},
+                                "raw_attributes": {}
+                            },
+                            {
+                                "id": "directory_user_01E1JJHG10ANRA2V6PAX3GD7TE",
+                                "idp_id": "8953",
+                                "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                                "first_name": "Rosalinda",
+                                "last_name": "Swift",
+                                "emails": [{
+                                    "primary": true,
+                                    "type": "work",
+                                    "value": null
+                                }],
+                                "username": "rosalinda@foo-corp.com",
+                                "state": "suspended",
+                                "created_at": "2021-06-25T19:07:33.155Z",
+                                "updated_at": "2021-06-25T19:07:33.155Z",
+                                "custom_attributes": {},
+                                "raw_attributes": {}
+                            }
+                        ],
+                        "list_metadata": { "before": null, "after": null }
+                    })
+                    .to_string(),
+                )
+                .create_async()
+                .await,
+            server
+                .mock("GET", "/directory_groups")
+                .match_query(Matcher::UrlEncoded(
+                    "directory".to_string(),
+                    "directory_01ECAZ4NV9QMV47GW873HDCX74".to_string(),
+                ))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "data": [{
+                            "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                            "idp_id": "02grqrue4294w24",
+                            "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                            "name": "Developers, Inc.",
+                            "created_at": "2021-06-25T19:07:33.155Z",
+                            "updated_at": "2021-06-25T19:07:33.155Z",
+                            "raw_attributes": {}
+                        }],
+                        "list_metadata": { "before": null, "after": null }
+                    })
+                    .to_string(),
+                )
+                .create_async()
+                .await,
+        ]
+    }
+
+    #[tokio::test]
+    async fn it_exports_scim_resources_that_round_trip_through_a_strict_scim_parser() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mocks = mock_directory(&mut server).await;
+
+        let mut output = Vec::new();
+        let summary = workos
+            .directory_sync()
+            .export_directory(
+                &DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+                ExportFormat::ScimJson,
+                &mut output,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            summary,
+            ExportDirectorySummary {
+                users: 2,
+                groups: 1
+            }
+        );
+
+        let list_response: strict::ListResponse = serde_json::from_slice(&output).unwrap();
+        assert_eq!(
+            list_response.schemas,
+            vec!["urn:ietf:params:scim:api:messages:2.0:ListResponse"]
+        );
+        assert_eq!(list_response.total_results, 3);
+        assert_eq!(list_response.items_per_page, 3);
+
+        let users = list_response.resources[..2]
+            .iter()
+            .map(|resource| strict::User::deserialize(resource).unwrap())
+            .collect::<Vec<_>>();
+        let groups = list_response.resources[2..]
+            .iter()
+            .map(|resource| strict::Group::deserialize(resource).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            users[0],
+            strict::User {
+                schemas: vec!["urn:ietf:params:scim:schemas:core:2.0:User".to_string()],
+                id: "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ".to_string(),
+                external_id: Some("2836".to_string()),
+                user_name: "marcelina@foo-corp.com".to_string(),
+                name: Some(strict::Name {
+                    given_name: Some("Marcelina".to_string()),
+                    family_name: Some("Davis".to_string()),
+                }),
+                emails: vec![strict::Email {
+                    value: "marcelina@foo-corp.com".to_string(),
+                    r#type: Some("work".to_string()),
+                    primary: Some(true),
+                }],
+                title: Some("Software Engineer".to_string()),
+                active: Some(true),
+                groups: vec![strict::Reference {
+                    value: "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z".to_string(),
+                    display: Some("Developers, Inc.".to_string()),
+                }],
+                meta: Some(strict::Meta {
+                    resource_type: "User".to_string(),
+                    created: "2021-06-25T19:07:33.155Z".to_string(),
+                    last_modified: "2021-06-25T19:07:33.155Z".to_string(),
+                }),
+            }
+        );
+        assert_eq!(users[1].user_name, "rosalinda@foo-corp.com");
+        assert_eq!(users[1].emails, vec![]);
+        assert_eq!(users[1].active, Some(false));
+
+        assert_eq!(
+            groups,
+            vec![strict::Group {
+                schemas: vec!["urn:ietf:params:scim:schemas:core:2.0:Group".to_string()],
+                id: "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z".to_string(),
+                external_id: Some("02grqrue4294w24".to_string()),
+                display_name: "Developers, Inc.".to_string(),
+                members: vec![strict::Reference {
+                    value: "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ".to_string(),
+                    display: Some("marcelina@foo-corp.com".to_string()),
+                }],
+                meta: Some(strict::Meta {
+                    resource_type: "Group".to_string(),
+                    created: "2021-06-25T19:07:33.155Z".to_string(),
+                    last_modified: "2021-06-25T19:07:33.155Z".to_string(),
+                }),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_exports_users_from_every_page() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let first_page_mock = server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::UrlEncoded(
+                "directory".to_string(),
+                "directory_01ECAZ4NV9QMV47GW873HDCX74".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                      "idp_id": "2836",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "first_name": "Marcelina",
+                      "last_name": "Davis",
+                      "emails": [],
+                      "username": "marcelina@foo-corp.com",
+                      "groups": [
+                        {
+                          "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                          "name": "Developers",
+                          "created_at": "2021-06-25T19:07:33.155Z",
+                          "updated_at": "2021-06-25T19:07:33.155Z"
+                        }
+                      ],
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "custom_attributes": {},
+                      "raw_attributes": {}
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ"
+                  }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let second_page_mock = server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(
+                    "directory".to_string(),
+                    "directory_01ECAZ4NV9QMV47GW873HDCX74".to_string(),
+                ),
+                Matcher::UrlEncoded(
+                    "after".to_string(),
+                    "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ".to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_user_01E1JJHG10ANRA2V6PAX3GD7TE",
+                      "idp_id": "8953",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "first_name": "Rosalinda",
+                      "last_name": "Swift",
+                      "emails": [],
+                      "username": null,
+                      "groups": [
+                        {
+                          "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                          "name": "Developers",
+                          "created_at": "2021-06-25T19:07:33.155Z",
+                          "updated_at": "2021-06-25T19:07:33.155Z"
+                        }
+                      ],
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "custom_attributes": {},
+                      "raw_attributes": {}
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": "directory_user_01E1JJHG10ANRA2V6PAX3GD7TE",
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let _groups_mock = server
+            .mock("GET", "/directory_groups")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                      "idp_id": "02grqrue4294w24",
+                      "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "name": "Developers",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "raw_attributes": {}
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let mut output = Vec::new();
+        let summary = workos
+            .directory_sync()
+            .export_directory(
+                &DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+                ExportFormat::ScimJson,
+                &mut output,
+            )
+            .await
+            .unwrap();
+
+        first_page_mock.assert_async().await;
+        second_page_mock.assert_async().await;
+        assert_eq!(
+            summary,
+            ExportDirectorySummary {
+                users: 2,
+                groups: 1
+            }
+        );
+
+        let list_response: strict::ListResponse = serde_json::from_slice(&output).unwrap();
+        let second_user = strict::User::deserialize(&list_response.resources[1]).unwrap();
+        assert_eq!(second_user.id, "directory_user_01E1JJHG10ANRA2V6PAX3GD7TE");
+        assert_eq!(second_user.user_name, "8953");
+
+        let group = strict::Group::deserialize(&list_response.resources[2]).unwrap();
+        assert_eq!(
+            group.members,
+            vec![
+                strict::Reference {
+                    value: "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ".to_string(),
+                    display: Some("marcelina@foo-corp.com".to_string()),
+                },
+                strict::Reference {
+                    value: "directory_user_01E1JJHG10ANRA2V6PAX3GD7TE".to_string(),
+                    display: None,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_exports_a_flat_csv() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mocks = mock_directory(&mut server).await;
+
+        let mut output = Vec::new();
+        workos
+            .directory_sync()
+            .export_directory(
+                &DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+                ExportFormat::Csv,
+                &mut output,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            [
+                "type,id,idp_id,name,email,first_name,last_name,state,groups",
+                "user,directory_user_01E1JG7J09H96KYP8HM9B0G5SJ,2836,marcelina@foo-corp.com,marcelina@foo-corp.com,Marcelina,Davis,active,directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                "user,directory_user_01E1JJHG10ANRA2V6PAX3GD7TE,8953,rosalinda@foo-corp.com,,Rosalinda,Swift,suspended,",
+                "group,directory_group_01E1JJS84MFPPQ3G655FHTKX6Z,02grqrue4294w24,\"Developers, Inc.\",,,,,",
+                "",
+            ]
+            .join("\r\n")
+        );
+    }
+
+    #[test]
+    fn it_escapes_formulas_in_csv_values() {
+        assert_eq!(
+            String::from_utf8(csv_record(&[
+                "=HYPERLINK(\"https://example.com\")",
+                "+1",
+                "-1",
+                "@SUM(A1:A2)",
+                "\t=1+1",
+                "\r=1+1",
+                "a=b",
+                "=1,2",
+            ]))
+            .unwrap(),
+            "\"'=HYPERLINK(\"\"https://example.com\"\")\",'+1,'-1,'@SUM(A1:A2),'\t=1+1,\"'\r=1+1\",a=b,\"'=1,2\"\r\n"
+        );
+    }
+}
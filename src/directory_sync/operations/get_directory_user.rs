@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use serde::de::DeserializeOwned;
 use thiserror::Error;
 
 use crate::directory_sync::{DirectorySync, DirectoryUser, DirectoryUserId};
@@ -44,6 +45,41 @@ pub trait GetDirectoryUser {
         &self,
         id: &DirectoryUserId,
     ) -> WorkOsResult<DirectoryUser, GetDirectoryUserError>;
+
+    /// Retrieves a [`DirectoryUser`] by its ID, decoding its custom attributes into
+    /// `TCustomAttributes`.
+    ///
+    /// [WorkOS Docs: Get a Directory User](https://workos.com/docs/reference/directory-sync/user/get)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde::Deserialize;
+    /// # use workos::WorkOsResult;
+    /// # use workos::directory_sync::*;
+    /// use workos::{ApiKey, WorkOs};
+    ///
+    /// #[derive(Deserialize)]
+    /// struct CustomAttributes {
+    ///     department: Option<String>,
+    /// }
+    ///
+    /// # async fn run() -> WorkOsResult<(), GetDirectoryUserError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let directory_user = workos
+    ///     .directory_sync()
+    ///     .get_directory_user_as::<CustomAttributes>(&DirectoryUserId::from(
+    ///         "directory_user_01E64QS50EAY48S0XJ1AA4WX4D",
+    ///     ))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_directory_user_as<TCustomAttributes: DeserializeOwned>(
+        &self,
+        id: &DirectoryUserId,
+    ) -> WorkOsResult<DirectoryUser<TCustomAttributes>, GetDirectoryUserError>;
 }
 
 #[async_trait]
@@ -52,6 +88,13 @@ impl GetDirectoryUser for DirectorySync<'_> {
         &self,
         id: &DirectoryUserId,
     ) -> WorkOsResult<DirectoryUser, GetDirectoryUserError> {
+        self.get_directory_user_as(id).await
+    }
+
+    async fn get_directory_user_as<TCustomAttributes: DeserializeOwned>(
+        &self,
+        id: &DirectoryUserId,
+    ) -> WorkOsResult<DirectoryUser<TCustomAttributes>, GetDirectoryUserError> {
         let url = self
             .workos
             .base_url()
@@ -64,7 +107,7 @@ impl GetDirectoryUser for DirectorySync<'_> {
             .send()
             .await?
             .handle_unauthorized_or_generic_error()?
-            .json::<DirectoryUser>()
+            .json::<DirectoryUser<TCustomAttributes>>()
             .await?;
 
         Ok(directory_user)
@@ -143,6 +186,63 @@ mod test {
         )
     }
 
+    #[tokio::test]
+    async fn it_decodes_the_custom_attributes_into_the_provided_type() {
+        #[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+        struct MyCustomAttributes {
+            department: String,
+        }
+
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock(
+                "GET",
+                "/directory_users/directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+            )
+            .with_status(200)
+            .with_body(
+                json!({
+                  "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                  "idp_id": "2836",
+                  "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                  "emails": [],
+                  "first_name": "Marcelina",
+                  "last_name": "Davis",
+                  "username": "marcelina@foo-corp.com",
+                  "state": "active",
+                  "created_at": "2021-06-25T19:07:33.155Z",
+                  "updated_at": "2021-06-25T19:07:33.155Z",
+                  "custom_attributes": {
+                    "department": "Engineering"
+                  },
+                  "raw_attributes": {}
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let directory_user = workos
+            .directory_sync()
+            .get_directory_user_as::<MyCustomAttributes>(&DirectoryUserId::from(
+                "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            directory_user.custom_attributes,
+            MyCustomAttributes {
+                department: "Engineering".to_string()
+            }
+        )
+    }
+
     #[tokio::test]
     async fn it_returns_an_error_when_the_get_directory_user_endpoint_returns_unauthorized() {
         let mut server = mockito::Server::new_async().await;
@@ -1,11 +1,12 @@
 use async_trait::async_trait;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::directory_sync::{DirectoryGroupId, DirectoryId, DirectorySync, DirectoryUser};
 use crate::{PaginatedList, PaginationParams, ResponseExt, WorkOsResult};
 
 /// A filter for [`ListDirectoryUsers`].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Copy, Serialize)]
 #[serde(untagged)]
 pub enum DirectoryUsersFilter<'a> {
     /// Retrieve directory users within the specified directory.
@@ -64,6 +65,42 @@ pub trait ListDirectoryUsers {
         &self,
         params: &ListDirectoryUsersParams<'_>,
     ) -> WorkOsResult<PaginatedList<DirectoryUser>, ()>;
+
+    /// Retrieves a list of [`DirectoryUser`]s, decoding their custom attributes into
+    /// `TCustomAttributes`.
+    ///
+    /// [WorkOS Docs: List Directory Users](https://workos.com/docs/reference/directory-sync/user/list)
+    ///
+    /// ```
+    /// # use serde::Deserialize;
+    /// # use workos::WorkOsResult;
+    /// # use workos::directory_sync::*;
+    /// use workos::{ApiKey, WorkOs};
+    ///
+    /// #[derive(Deserialize)]
+    /// struct CustomAttributes {
+    ///     department: Option<String>,
+    /// }
+    ///
+    /// # async fn run() -> WorkOsResult<(), ()> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let paginated_users = workos
+    ///     .directory_sync()
+    ///     .list_directory_users_as::<CustomAttributes>(&ListDirectoryUsersParams {
+    ///         filter: DirectoryUsersFilter::Directory {
+    ///             directory: &DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+    ///         },
+    ///         pagination: Default::default(),
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_directory_users_as<TCustomAttributes: DeserializeOwned>(
+        &self,
+        params: &ListDirectoryUsersParams<'_>,
+    ) -> WorkOsResult<PaginatedList<DirectoryUser<TCustomAttributes>>, ()>;
 }
 
 #[async_trait]
@@ -72,6 +109,13 @@ impl ListDirectoryUsers for DirectorySync<'_> {
         &self,
         params: &ListDirectoryUsersParams<'_>,
     ) -> WorkOsResult<PaginatedList<DirectoryUser>, ()> {
+        self.list_directory_users_as(params).await
+    }
+
+    async fn list_directory_users_as<TCustomAttributes: DeserializeOwned>(
+        &self,
+        params: &ListDirectoryUsersParams<'_>,
+    ) -> WorkOsResult<PaginatedList<DirectoryUser<TCustomAttributes>>, ()> {
         let url = self.workos.base_url().join("/directory_users")?;
         let directory_users = self
             .workos
@@ -82,7 +126,7 @@ impl ListDirectoryUsers for DirectorySync<'_> {
             .send()
             .await?
             .handle_unauthorized_or_generic_error()?
-            .json::<PaginatedList<DirectoryUser>>()
+            .json::<PaginatedList<DirectoryUser<TCustomAttributes>>>()
             .await?;
 
         Ok(directory_users)
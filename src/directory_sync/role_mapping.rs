@@ -0,0 +1,596 @@
+use thiserror::Error;
+
+use crate::directory_sync::{
+    DirectoryGroup, DirectoryGroupsFilter, DirectoryUser, ListDirectoryGroups,
+    ListDirectoryGroupsParams,
+};
+use crate::user_management::{
+    ListOrganizationMemberships, ListOrganizationMembershipsParams, ListUsers, ListUsersParams,
+    OrganizationMembershipId, OrganizationRole, UpdateOrganizationMembership,
+    UpdateOrganizationMembershipParams, UserId,
+};
+use crate::{paginate_all, PaginationParams, WorkOs, WorkOsError, WorkOsResult};
+
+/// A pattern that a [`GroupRoleMapping`] rule matches directory groups with.
+///
+/// Patterns may contain `*`, which matches any sequence of characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupPattern {
+    /// Matches the name of the directory group, ignoring case.
+    Name(String),
+
+    /// Matches the ID of the directory group assigned by the Directory Provider.
+    IdpId(String),
+}
+
+impl GroupPattern {
+    /// Returns whether the pattern matches the directory group.
+    pub fn matches(&self, group: &DirectoryGroup) -> bool {
+        match self {
+            GroupPattern::Name(pattern) => {
+                glob_match(&pattern.to_lowercase(), &group.name.to_lowercase())
+            }
+            GroupPattern::IdpId(pattern) => glob_match(pattern, &group.idp_id),
+        }
+    }
+}
+
+fn glob_match(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let value = value.chars().collect::<Vec<_>>();
+
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack = None;
+
+    while v < value.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, v));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == value[v] {
+            p += 1;
+            v += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            v = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|char| *char == '*')
+}
+
+/// A rule of a [`GroupRoleMapping`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupRoleRule {
+    /// The pattern the directory groups are matched with.
+    pub pattern: GroupPattern,
+
+    /// The slug of the role for members of a matching directory group.
+    pub role_slug: String,
+
+    /// The priority of the rule. The matching rule with the highest priority wins.
+    pub priority: i32,
+}
+
+/// A declarative mapping from directory groups to organization roles.
+///
+/// # Examples
+///
+/// ```
+/// # use workos::directory_sync::*;
+/// let mapping = GroupRoleMapping::new()
+///     .with_rule(GroupPattern::Name("Engineering*".to_string()), "member", 0)
+///     .with_rule(GroupPattern::Name("*Admins".to_string()), "admin", 10)
+///     .with_default_role("viewer");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroupRoleMapping {
+    rules: Vec<GroupRoleRule>,
+    default_role_slug: Option<String>,
+}
+
+impl GroupRoleMapping {
+    /// Returns a new [`GroupRoleMapping`] without rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule that assigns `role_slug` to members of directory groups matching
+    /// `pattern`.
+    ///
+    /// When rules with the same priority match, the rule added first wins.
+    pub fn with_rule(
+        mut self,
+        pattern: GroupPattern,
+        role_slug: impl Into<String>,
+        priority: i32,
+    ) -> Self {
+        self.rules.push(GroupRoleRule {
+            pattern,
+            role_slug: role_slug.into(),
+            priority,
+        });
+        self
+    }
+
+    /// Sets the role for directory users that are not a member of any matching group.
+    pub fn with_default_role(mut self, role_slug: impl Into<String>) -> Self {
+        self.default_role_slug = Some(role_slug.into());
+        self
+    }
+
+    /// Returns the rules of the mapping, in the order they were added.
+    pub fn rules(&self) -> &[GroupRoleRule] {
+        &self.rules
+    }
+
+    /// Returns the role for a member of the given directory groups, if any.
+    pub fn role_for(&self, groups: &[DirectoryGroup]) -> Option<OrganizationRole> {
+        let mut matched: Option<&GroupRoleRule> = None;
+        for rule in &self.rules {
+            let is_better = matched.is_none_or(|matched| rule.priority > matched.priority);
+            if is_better && groups.iter().any(|group| rule.pattern.matches(group)) {
+                matched = Some(rule);
+            }
+        }
+
+        matched
+            .map(|rule| &rule.role_slug)
+            .or(self.default_role_slug.as_ref())
+            .map(|slug| OrganizationRole { slug: slug.clone() })
+    }
+}
+
+/// An error returned from [`GroupRoleSync::sync_directory_user`].
+#[derive(Debug, Error)]
+pub enum GroupRoleSyncError {
+    /// The directory user does not belong to an organization.
+    #[error("directory user does not belong to an organization")]
+    MissingOrganization,
+
+    /// The directory user does not have an email address to find the user by.
+    #[error("directory user does not have an email address")]
+    MissingEmail,
+
+    /// The directory groups of the directory user could not be listed.
+    #[error("failed to list the directory groups of the directory user")]
+    ListDirectoryGroups,
+}
+
+impl From<GroupRoleSyncError> for WorkOsError<GroupRoleSyncError> {
+    fn from(err: GroupRoleSyncError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+/// A change of the role of a user in an organization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoleChange {
+    /// The ID of the organization membership.
+    pub organization_membership_id: OrganizationMembershipId,
+
+    /// The ID of the user.
+    pub user_id: UserId,
+
+    /// The role before the change.
+    pub previous_role: OrganizationRole,
+
+    /// The role after the change.
+    pub role: OrganizationRole,
+
+    /// Whether the change was applied. This is `false` in dry-run mode.
+    pub applied: bool,
+}
+
+/// The outcome of [`GroupRoleSync::sync_directory_user`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupRoleSyncOutcome {
+    /// The user already has the desired role.
+    Unchanged,
+
+    /// The role of the user was changed, or would be in dry-run mode.
+    Changed(RoleChange),
+
+    /// No rule matched the directory groups of the directory user, and the mapping has
+    /// no default role.
+    NoMatchingRule,
+
+    /// There is no user with the email address of the directory user in the
+    /// organization.
+    NoMembership,
+}
+
+/// Assigns organization roles to users based on their directory groups, using a
+/// [`GroupRoleMapping`].
+///
+/// Users are matched to directory users by the directory user's
+/// [primary email](DirectoryUser::primary_email).
+///
+/// # Examples
+///
+/// ```
+/// # use workos::WorkOsResult;
+/// # use workos::directory_sync::*;
+/// use workos::{ApiKey, WorkOs};
+///
+/// # async fn run(directory_user: DirectoryUser) -> WorkOsResult<(), GroupRoleSyncError> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+/// let mapping = GroupRoleMapping::new()
+///     .with_rule(GroupPattern::Name("*Admins".to_string()), "admin", 10)
+///     .with_default_role("member");
+///
+/// let role_sync = GroupRoleSync::new(&workos, mapping).with_dry_run(true);
+///
+/// if let GroupRoleSyncOutcome::Changed(change) =
+///     role_sync.sync_directory_user(&directory_user).await?
+/// {
+///     println!(
+///         "would change {} from {} to {}",
+///         change.user_id, change.previous_role.slug, change.role.slug
+///     );
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct GroupRoleSync<'a> {
+    workos: &'a WorkOs,
+    mapping: GroupRoleMapping,
+    dry_run: bool,
+}
+
+impl<'a> GroupRoleSync<'a> {
+    /// Returns a new [`GroupRoleSync`] instance that applies the provided mapping.
+    pub fn new(workos: &'a WorkOs, mapping: GroupRoleMapping) -> Self {
+        Self {
+            workos,
+            mapping,
+            dry_run: false,
+        }
+    }
+
+    /// Sets whether to only report the planned changes, without applying them.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Computes the desired role of a directory user from their directory groups and
+    /// updates their organization membership if it has a different role.
+    pub async fn sync_directory_user<TCustomAttributes: Sync>(
+        &self,
+        directory_user: &DirectoryUser<TCustomAttributes>,
+    ) -> WorkOsResult<GroupRoleSyncOutcome, GroupRoleSyncError> {
+        let organization_id = directory_user
+            .organization_id
+            .as_ref()
+            .ok_or(GroupRoleSyncError::MissingOrganization)?;
+        let email = directory_user
+            .primary_email()
+            .ok_or(GroupRoleSyncError::MissingEmail)?;
+
+        let groups = self
+            .list_all_groups(directory_user)
+            .await
+            .map_err(|err| err.map_operation(|()| GroupRoleSyncError::ListDirectoryGroups))?;
+
+        let Some(role) = self.mapping.role_for(&groups) else {
+            return Ok(GroupRoleSyncOutcome::NoMatchingRule);
+        };
+
+        let user_management = self.workos.user_management();
+
+        let users = user_management
+            .list_users(&ListUsersParams {
+                email: Some(email),
+                organization_id: Some(organization_id),
+                ..Default::default()
+            })
+            .await
+            .map_err(|err| err.map_operation(|err| match err {}))?;
+        let Some(user) = users.data.into_iter().next() else {
+            return Ok(GroupRoleSyncOutcome::NoMembership);
+        };
+
+        let memberships = user_management
+            .list_organization_memberships(&ListOrganizationMembershipsParams {
+                organization_id,
+                user_id: Some(&user.id),
+                pagination: Default::default(),
+            })
+            .await
+            .map_err(|err| err.map_operation(|err| match err {}))?;
+        let Some(membership) = memberships.data.into_iter().next() else {
+            return Ok(GroupRoleSyncOutcome::NoMembership);
+        };
+
+        if membership.role == role {
+            return Ok(GroupRoleSyncOutcome::Unchanged);
+        }
+
+        if !self.dry_run {
+            user_management
+                .update_organization_membership(&UpdateOrganizationMembershipParams {
+                    organization_membership_id: &membership.id,
+                    role_slug: Some(&role.slug),
+                })
+                .await
+                .map_err(|err| err.map_operation(|err| match err {}))?;
+        }
+
+        Ok(GroupRoleSyncOutcome::Changed(RoleChange {
+            organization_membership_id: membership.id,
+            user_id: user.id,
+            previous_role: membership.role,
+            role,
+            applied: !self.dry_run,
+        }))
+    }
+
+    async fn list_all_groups<TCustomAttributes>(
+        &self,
+        directory_user: &DirectoryUser<TCustomAttributes>,
+    ) -> WorkOsResult<Vec<DirectoryGroup>, ()> {
+        paginate_all(|after| async move {
+            self.workos
+                .directory_sync()
+                .list_directory_groups(&ListDirectoryGroupsParams {
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        ..Default::default()
+                    },
+                    filter: DirectoryGroupsFilter::User {
+                        user: &directory_user.id,
+                    },
+                })
+                .await
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockito::{Matcher, Mock, ServerGuard};
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::ApiKey;
+
+    fn group(name: &str, idp_id: &str) -> DirectoryGroup {
+        serde_json::from_value(json!({
+            "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+            "idp_id": idp_id,
+            "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+            "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+            "name": name,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z",
+            "raw_attributes": {}
+        }))
+        .unwrap()
+    }
+
+    fn directory_user() -> DirectoryUser {
+        serde_json::from_value(json!({
+            "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+            "idp_id": "2836",
+            "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+            "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+            "first_name": "Marcelina",
+            "last_name": "Davis",
+            "emails": [{
+                "primary": true,
+                "type": "work",
+                "value": "marcelina@foo-corp.com"
+            }],
+            "username": "marcelina@foo-corp.com",
+            "state": "active",
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z",
+            "custom_attributes": {},
+            "raw_attributes": {}
+        }))
+        .unwrap()
+    }
+
+    fn mapping() -> GroupRoleMapping {
+        GroupRoleMapping::new()
+            .with_rule(GroupPattern::Name("engineering*".to_string()), "member", 0)
+            .with_rule(GroupPattern::Name("*Admins".to_string()), "admin", 10)
+            .with_rule(GroupPattern::IdpId("02grqrue*".to_string()), "billing", 5)
+    }
+
+    #[test]
+    fn it_returns_the_role_of_the_matching_rule_with_the_highest_priority() {
+        let groups = vec![
+            group("Engineering EU", "01abc"),
+            group("Platform Admins", "01def"),
+            group("Finance", "02grqrue4294w24"),
+        ];
+
+        assert_eq!(
+            mapping().role_for(&groups),
+            Some(OrganizationRole {
+                slug: "admin".to_string()
+            })
+        );
+        assert_eq!(
+            mapping().role_for(&groups[..1]),
+            Some(OrganizationRole {
+                slug: "member".to_string()
+            })
+        );
+        assert_eq!(mapping().role_for(&[group("Sales", "03xyz")]), None);
+        assert_eq!(
+            mapping()
+                .with_default_role("viewer")
+                .role_for(&[group("Sales", "03xyz")]),
+            Some(OrganizationRole {
+                slug: "viewer".to_string()
+            })
+        );
+    }
+
+    async fn mock_membership(server: &mut ServerGuard) -> Vec<Mock> {
+        vec![
+            server
+                .mock("GET", "/directory_groups")
+                .match_query(Matcher::UrlEncoded(
+                    "user".to_string(),
+                    "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ".to_string(),
+                ))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "data": [group("Platform Admins", "01def")],
+                        "list_metadata": { "before": null, "after": null }
+                    })
+                    .to_string(),
+                )
+                .create_async()
+                .await,
+            server
+                .mock("GET", "/user_management/users")
+                .match_query(Matcher::UrlEncoded(
+                    "email".to_string(),
+                    "marcelina@foo-corp.com".to_string(),
+                ))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "data": [{
+                            "object": "user",
+                            "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                            "email": "marcelina@foo-corp.com",
+                            "first_name": "Marcelina",
+                            "last_name": "Davis",
+                            "email_verified": true,
+                            "profile_picture_url": null,
+                            "last_sign_in_at": null,
+                            "external_id": null,
+                            "metadata": {},
+                            "created_at": "2021-06-25T19:07:33.155Z",
+                            "updated_at": "2021-06-25T19:07:33.155Z"
+                        }],
+                        "list_metadata": { "before": null, "after": null }
+                    })
+                    .to_string(),
+                )
+                .create_async()
+                .await,
+            server
+                .mock("GET", "/user_management/organization_memberships")
+                .match_query(Matcher::UrlEncoded(
+                    "user_id".to_string(),
+                    "user_01E4ZCR3C56J083X43JQXF3JK5".to_string(),
+                ))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "data": [{
+                            "object": "organization_membership",
+                            "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+                            "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                            "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+                            "role": { "slug": "member" },
+                            "status": "active",
+                            "created_at": "2021-06-25T19:07:33.155Z",
+                            "updated_at": "2021-06-25T19:07:33.155Z"
+                        }],
+                        "list_metadata": { "before": null, "after": null }
+                    })
+                    .to_string(),
+                )
+                .create_async()
+                .await,
+        ]
+    }
+
+    fn expected_change(applied: bool) -> GroupRoleSyncOutcome {
+        GroupRoleSyncOutcome::Changed(RoleChange {
+            organization_membership_id: OrganizationMembershipId::from(
+                "om_01E4ZCR3C56J083X43JQXF3JK5",
+            ),
+            user_id: UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"),
+            previous_role: OrganizationRole {
+                slug: "member".to_string(),
+            },
+            role: OrganizationRole {
+                slug: "admin".to_string(),
+            },
+            applied,
+        })
+    }
+
+    #[tokio::test]
+    async fn it_updates_the_organization_membership_with_the_mapped_role() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mocks = mock_membership(&mut server).await;
+        let update_mock = server
+            .mock(
+                "PUT",
+                "/user_management/organization_memberships/om_01E4ZCR3C56J083X43JQXF3JK5",
+            )
+            .match_body(Matcher::Json(json!({ "role_slug": "admin" })))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "object": "organization_membership",
+                    "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+                    "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                    "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+                    "role": { "slug": "admin" },
+                    "status": "active",
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let outcome = GroupRoleSync::new(&workos, mapping())
+            .sync_directory_user(&directory_user())
+            .await
+            .unwrap();
+
+        update_mock.assert_async().await;
+        assert_eq!(outcome, expected_change(true));
+    }
+
+    #[tokio::test]
+    async fn it_only_reports_the_planned_change_in_dry_run_mode() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mocks = mock_membership(&mut server).await;
+        let update_mock = server
+            .mock(
+                "PUT",
+                "/user_management/organization_memberships/om_01E4ZCR3C56J083X43JQXF3JK5",
+            )
+            .expect(0)
+            .create_async()
+            .await;
+
+        let outcome = GroupRoleSync::new(&workos, mapping())
+            .with_dry_run(true)
+            .sync_directory_user(&directory_user())
+            .await
+            .unwrap();
+
+        update_mock.assert_async().await;
+        assert_eq!(outcome, expected_change(false));
+    }
+}
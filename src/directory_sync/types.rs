@@ -2,8 +2,10 @@ mod directory;
 mod directory_group;
 mod directory_type;
 mod directory_user;
+mod provider_attributes;
 
 pub use directory::*;
 pub use directory_group::*;
 pub use directory_type::*;
 pub use directory_user::*;
+pub use provider_attributes::*;
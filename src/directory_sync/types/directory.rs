@@ -7,7 +7,7 @@ use crate::organizations::OrganizationId;
 use crate::{KnownOrUnknown, Timestamps};
 
 /// The ID of a [`Directory`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct DirectoryId(String);
 
 impl Display for DirectoryId {
@@ -29,7 +29,7 @@ impl From<&str> for DirectoryId {
 }
 
 /// The state of a [`Directory`].
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum DirectoryState {
     /// The directory is inactve.
@@ -51,7 +51,7 @@ pub enum DirectoryState {
 }
 
 /// [WorkOS Docs: Directory](https://workos.com/docs/reference/directory-sync/directory)
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Directory {
     /// The ID of the directory.
     pub id: DirectoryId,
@@ -83,7 +83,7 @@ mod test {
     use super::{Directory, DirectoryId, DirectoryState};
     use crate::directory_sync::DirectoryType;
     use crate::organizations::OrganizationId;
-    use crate::{KnownOrUnknown, Timestamp, Timestamps};
+    use crate::{KnownOrUnknown, ProviderProtocol, Timestamp, Timestamps};
 
     #[test]
     fn it_deserializes_a_directory() {
@@ -141,4 +141,17 @@ mod test {
             KnownOrUnknown::Unknown("UnknownType".to_string())
         )
     }
+
+    #[test]
+    fn it_returns_the_catalog_metadata_of_a_directory_type() {
+        let known: KnownOrUnknown<DirectoryType, String> =
+            KnownOrUnknown::Known(DirectoryType::GoogleWorkspace);
+        assert_eq!(known.display_name(), "Google Workspace");
+        assert_eq!(known.protocol(), Some(ProviderProtocol::Oauth));
+
+        let unknown: KnownOrUnknown<DirectoryType, String> =
+            KnownOrUnknown::Unknown("UnknownType".to_string());
+        assert_eq!(unknown.display_name(), "UnknownType");
+        assert_eq!(unknown.docs_url(), None);
+    }
 }
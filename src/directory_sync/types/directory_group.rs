@@ -52,6 +52,9 @@ pub struct DirectoryGroup {
     pub timestamps: Timestamps,
 
     /// The raw attributes received from the Identity Provider.
+    ///
+    /// Empty if the response did not include them.
+    #[serde(default)]
     pub raw_attributes: RawAttributes,
 }
 
@@ -102,4 +105,26 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn it_deserializes_a_directory_group_without_raw_attributes() {
+        let directory_group: DirectoryGroup = serde_json::from_str(
+            &json!({
+              "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+              "idp_id": "02grqrue4294w24",
+              "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "organization_id": null,
+              "name": "Developers",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            directory_group.raw_attributes,
+            RawAttributes(HashMap::new())
+        )
+    }
 }
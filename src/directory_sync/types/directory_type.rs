@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{KnownOrUnknown, ProviderProtocol};
+
 /// The type of a [`Directory`](crate::directory_sync::Directory).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DirectoryType {
@@ -99,3 +101,116 @@ pub enum DirectoryType {
     #[serde(rename = "workday")]
     Workday,
 }
+
+impl DirectoryType {
+    /// Returns the human-readable name of the directory type.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::AzureScimV2_0 => "Azure AD SCIM v2.0",
+            Self::BambooHr => "BambooHR",
+            Self::BreatheHr => "Breathe HR",
+            Self::CyberArkScimV2_0 => "CyberArk SCIM v2.0",
+            Self::GenericScimV1_1 => "Generic SCIM v1.1",
+            Self::GenericScimV2_0 => "Generic SCIM v2.0",
+            Self::GoogleWorkspace => "Google Workspace",
+            Self::Hibob => "Hibob",
+            Self::JumpCloudScimV2_0 => "JumpCloud SCIM v2.0",
+            Self::OktaScimV1_1 => "Okta SCIM v1.1",
+            Self::OktaScimV2_0 => "Okta SCIM v2.0",
+            Self::OneLoginScimV2_0 => "OneLogin SCIM v2.0",
+            Self::PeopleHr => "People HR",
+            Self::PingFederateScimV2_0 => "PingFederate SCIM v2.0",
+            Self::Rippling => "Rippling",
+            Self::Workday => "Workday",
+        }
+    }
+
+    /// Returns the protocol family of the directory type.
+    pub fn protocol(&self) -> ProviderProtocol {
+        match self {
+            Self::AzureScimV2_0
+            | Self::CyberArkScimV2_0
+            | Self::GenericScimV1_1
+            | Self::GenericScimV2_0
+            | Self::JumpCloudScimV2_0
+            | Self::OktaScimV1_1
+            | Self::OktaScimV2_0
+            | Self::OneLoginScimV2_0
+            | Self::PingFederateScimV2_0 => ProviderProtocol::Scim,
+            Self::GoogleWorkspace => ProviderProtocol::Oauth,
+            Self::BambooHr
+            | Self::BreatheHr
+            | Self::Hibob
+            | Self::PeopleHr
+            | Self::Rippling
+            | Self::Workday => ProviderProtocol::HrisApi,
+        }
+    }
+
+    /// Returns the URL of the WorkOS integration guide for the directory type.
+    pub fn docs_url(&self) -> &'static str {
+        match self {
+            Self::AzureScimV2_0 => "https://workos.com/docs/integrations/azure-ad-scim",
+            Self::BambooHr => "https://workos.com/docs/integrations/bamboo-hr",
+            Self::BreatheHr => "https://workos.com/docs/integrations/breathe-hr",
+            Self::CyberArkScimV2_0 => "https://workos.com/docs/integrations/cyberark-scim",
+            Self::GenericScimV1_1 => "https://workos.com/docs/integrations/scim-v1-1",
+            Self::GenericScimV2_0 => "https://workos.com/docs/integrations/scim-v2-0",
+            Self::GoogleWorkspace => "https://workos.com/docs/integrations/google-workspace",
+            Self::Hibob => "https://workos.com/docs/integrations/hibob",
+            Self::JumpCloudScimV2_0 => "https://workos.com/docs/integrations/jumpcloud-scim",
+            Self::OktaScimV1_1 => "https://workos.com/docs/integrations/okta-scim-v1-1",
+            Self::OktaScimV2_0 => "https://workos.com/docs/integrations/okta-scim-v2-0",
+            Self::OneLoginScimV2_0 => "https://workos.com/docs/integrations/onelogin-scim",
+            Self::PeopleHr => "https://workos.com/docs/integrations/people-hr",
+            Self::PingFederateScimV2_0 => "https://workos.com/docs/integrations/pingfederate-scim",
+            Self::Rippling => "https://workos.com/docs/integrations/rippling",
+            Self::Workday => "https://workos.com/docs/integrations/workday",
+        }
+    }
+
+    /// Returns whether an organization's IT admin can set up a directory of this type
+    /// themselves through the [Admin Portal](crate::admin_portal).
+    ///
+    /// All directory types are currently self-serve.
+    pub fn is_self_serve(&self) -> bool {
+        true
+    }
+}
+
+impl KnownOrUnknown<DirectoryType, String> {
+    /// Returns the human-readable name of the directory type, or the raw value if the
+    /// directory type is unknown.
+    pub fn display_name(&self) -> &str {
+        match self {
+            KnownOrUnknown::Known(known) => known.display_name(),
+            KnownOrUnknown::Unknown(unknown) => unknown,
+        }
+    }
+
+    /// Returns the protocol family of the directory type, if it is known.
+    pub fn protocol(&self) -> Option<ProviderProtocol> {
+        match self {
+            KnownOrUnknown::Known(known) => Some(known.protocol()),
+            KnownOrUnknown::Unknown(_) => None,
+        }
+    }
+
+    /// Returns the URL of the WorkOS integration guide for the directory type, if it is
+    /// known.
+    pub fn docs_url(&self) -> Option<&'static str> {
+        match self {
+            KnownOrUnknown::Known(known) => Some(known.docs_url()),
+            KnownOrUnknown::Unknown(_) => None,
+        }
+    }
+
+    /// Returns whether the directory type is known and can be set up through the
+    /// [Admin Portal](crate::admin_portal).
+    pub fn is_self_serve(&self) -> bool {
+        match self {
+            KnownOrUnknown::Known(known) => known.is_self_serve(),
+            KnownOrUnknown::Unknown(_) => false,
+        }
+    }
+}
@@ -4,7 +4,7 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::directory_sync::DirectoryId;
+use crate::directory_sync::{DirectoryGroupId, DirectoryId};
 use crate::organizations::OrganizationId;
 use crate::{KnownOrUnknown, RawAttributes, Timestamps};
 
@@ -49,6 +49,13 @@ pub struct DirectoryUser<TCustomAttributes = HashMap<String, Value>> {
     /// The username of the directory user.
     pub username: Option<String>,
 
+    /// The primary email address of the directory user.
+    ///
+    /// Prefer [`primary_email`](DirectoryUser::primary_email), which falls back to
+    /// [`emails`](DirectoryUser::emails) when this is not set.
+    #[serde(default)]
+    pub email: Option<String>,
+
     /// The emails of the directory user.
     pub emails: Vec<DirectoryUserEmail>,
 
@@ -58,6 +65,18 @@ pub struct DirectoryUser<TCustomAttributes = HashMap<String, Value>> {
     /// The last name of the directory user.
     pub last_name: Option<String>,
 
+    /// The job title of the directory user.
+    #[serde(default)]
+    pub job_title: Option<String>,
+
+    /// The directory groups the directory user is a member of.
+    #[serde(default)]
+    pub groups: Vec<DirectoryUserGroup>,
+
+    /// The role of the directory user.
+    #[serde(default)]
+    pub role: Option<DirectoryUserRole>,
+
     /// The state of the directory user.
     pub state: KnownOrUnknown<DirectoryUserState, String>,
 
@@ -72,6 +91,21 @@ pub struct DirectoryUser<TCustomAttributes = HashMap<String, Value>> {
     pub timestamps: Timestamps,
 }
 
+impl<TCustomAttributes> DirectoryUser<TCustomAttributes> {
+    /// Returns the primary email address of the directory user.
+    ///
+    /// This is the [`email`](DirectoryUser::email) if set, and otherwise the value of the
+    /// first email address in [`emails`](DirectoryUser::emails) marked as primary.
+    pub fn primary_email(&self) -> Option<&str> {
+        self.email.as_deref().or_else(|| {
+            self.emails
+                .iter()
+                .find(|email| email.is_primary())
+                .and_then(|email| email.value.as_deref())
+        })
+    }
+}
+
 /// The state of a [`DirectoryUser`].
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -99,6 +133,40 @@ pub struct DirectoryUserEmail {
     pub value: Option<String>,
 }
 
+impl DirectoryUserEmail {
+    /// Returns whether this is the directory user's primary email address.
+    pub fn is_primary(&self) -> bool {
+        self.primary == Some(true)
+    }
+}
+
+/// A directory group embedded in a [`DirectoryUser`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DirectoryUserGroup {
+    /// The ID of the directory group.
+    pub id: DirectoryGroupId,
+
+    /// The name of the directory group.
+    pub name: String,
+
+    /// The raw attributes received from the Directory Provider.
+    #[serde(default)]
+    pub raw_attributes: RawAttributes,
+
+    /// The timestamps for the directory group.
+    #[serde(flatten)]
+    pub timestamps: Timestamps,
+}
+
+/// The role of a [`DirectoryUser`].
+///
+/// [WorkOS Docs: Role assignment](https://workos.com/docs/directory-sync/identity-provider-role-assignment)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DirectoryUserRole {
+    /// The slug of the role.
+    pub slug: String,
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
@@ -107,8 +175,10 @@ mod test {
     use serde_json::{json, Value};
 
     use super::{
-        DirectoryId, DirectoryUser, DirectoryUserEmail, DirectoryUserId, DirectoryUserState,
+        DirectoryGroupId, DirectoryId, DirectoryUser, DirectoryUserEmail, DirectoryUserGroup,
+        DirectoryUserId, DirectoryUserRole, DirectoryUserState,
     };
+    use crate::directory_sync::DirectoryType;
     use crate::organizations::OrganizationId;
     use crate::{KnownOrUnknown, RawAttributes, Timestamp, Timestamps};
 
@@ -122,6 +192,8 @@ mod test {
                 "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
                 "first_name": "Marcelina",
                 "last_name": "Davis",
+                "job_title": "Software Engineer",
+                "email": "marcelina@foo-corp.com",
                 "emails": [
                     {
                         "primary": true,
@@ -140,6 +212,9 @@ mod test {
                     }
                 ],
                 "state": "active",
+                "role": {
+                    "slug": "member"
+                },
                 "created_at": "2021-06-25T19:07:33.155Z",
                 "updated_at": "2021-06-25T19:07:33.155Z",
                 "custom_attributes": {
@@ -170,6 +245,7 @@ mod test {
                 directory_id: DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
                 organization_id: Some(OrganizationId::from("org_01EZTR6WYX1A0DSE2CYMGXQ24Y")),
                 username: Some("marcelina@foo-corp.com".to_string()),
+                email: Some("marcelina@foo-corp.com".to_string()),
                 emails: vec![DirectoryUserEmail {
                     primary: Some(true),
                     r#type: Some("work".to_string()),
@@ -177,6 +253,19 @@ mod test {
                 }],
                 first_name: Some("Marcelina".to_string()),
                 last_name: Some("Davis".to_string()),
+                job_title: Some("Software Engineer".to_string()),
+                groups: vec![DirectoryUserGroup {
+                    id: DirectoryGroupId::from("directory_group_01E64QTDNS0EGJ0FMCVY9BWGZT"),
+                    name: "Engineering".to_string(),
+                    raw_attributes: RawAttributes(HashMap::new()),
+                    timestamps: Timestamps {
+                        created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                        updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                    },
+                }],
+                role: Some(DirectoryUserRole {
+                    slug: "member".to_string()
+                }),
                 state: KnownOrUnknown::Known(DirectoryUserState::Active),
                 custom_attributes: expected_custom_attributes,
                 raw_attributes: RawAttributes(expected_raw_attributes),
@@ -240,4 +329,232 @@ mod test {
             }
         )
     }
+
+    /// Returns a full `GET /directory_users/:id` response for a directory of the given type.
+    fn fixture(directory_type: &DirectoryType) -> &'static str {
+        match directory_type {
+            DirectoryType::AzureScimV2_0 => {
+                include_str!("../../../fixtures/directory_users/azure_scim_v2_0.json")
+            }
+            DirectoryType::BambooHr => {
+                include_str!("../../../fixtures/directory_users/bamboo_hr.json")
+            }
+            DirectoryType::BreatheHr => {
+                include_str!("../../../fixtures/directory_users/breathe_hr.json")
+            }
+            DirectoryType::CyberArkScimV2_0 => {
+                include_str!("../../../fixtures/directory_users/cyberark_scim_v2_0.json")
+            }
+            DirectoryType::GenericScimV1_1 => {
+                include_str!("../../../fixtures/directory_users/generic_scim_v1_1.json")
+            }
+            DirectoryType::GenericScimV2_0 => {
+                include_str!("../../../fixtures/directory_users/generic_scim_v2_0.json")
+            }
+            DirectoryType::GoogleWorkspace => {
+                include_str!("../../../fixtures/directory_users/google_workspace.json")
+            }
+            DirectoryType::Hibob => {
+                include_str!("../../../fixtures/directory_users/hibob.json")
+            }
+            DirectoryType::JumpCloudScimV2_0 => {
+                include_str!("../../../fixtures/directory_users/jumpcloud_scim_v2_0.json")
+            }
+            DirectoryType::OktaScimV1_1 => {
+                include_str!("../../../fixtures/directory_users/okta_scim_v1_1.json")
+            }
+            DirectoryType::OktaScimV2_0 => {
+                include_str!("../../../fixtures/directory_users/okta_scim_v2_0.json")
+            }
+            DirectoryType::OneLoginScimV2_0 => {
+                include_str!("../../../fixtures/directory_users/onelogin_scim_v2_0.json")
+            }
+            DirectoryType::PeopleHr => {
+                include_str!("../../../fixtures/directory_users/people_hr.json")
+            }
+            DirectoryType::PingFederateScimV2_0 => {
+                include_str!("../../../fixtures/directory_users/pingfederate_scim_v2_0.json")
+            }
+            DirectoryType::Rippling => {
+                include_str!("../../../fixtures/directory_users/rippling.json")
+            }
+            DirectoryType::Workday => {
+                include_str!("../../../fixtures/directory_users/workday.json")
+            }
+        }
+    }
+
+    #[test]
+    fn it_deserializes_directory_users_from_every_directory_type() {
+        // (directory type, primary email, job title, group names, role slug, state)
+        let expectations = [
+            (
+                DirectoryType::AzureScimV2_0,
+                Some("marcelina.davis@foo-corp.com"),
+                Some("Software Engineer"),
+                vec!["Engineering"],
+                None,
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::BambooHr,
+                Some("mdavis@foo-corp.com"),
+                Some("Software Engineer"),
+                vec!["Engineering"],
+                None,
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::BreatheHr,
+                Some("marcelina@foo-corp.com"),
+                None,
+                vec![],
+                None,
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::CyberArkScimV2_0,
+                Some("marcelina@foo-corp.com"),
+                None,
+                vec!["Developers"],
+                None,
+                DirectoryUserState::Inactive,
+            ),
+            (
+                DirectoryType::GenericScimV1_1,
+                Some("marcelina@foo-corp.com"),
+                Some("Staff Engineer"),
+                vec![],
+                None,
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::GenericScimV2_0,
+                None,
+                None,
+                vec![],
+                None,
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::GoogleWorkspace,
+                Some("marcelina@foo-corp.com"),
+                Some("Software Engineer"),
+                vec!["Engineering"],
+                None,
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::Hibob,
+                Some("marcelina@foo-corp.com"),
+                Some("Engineering Manager"),
+                vec!["Engineering"],
+                Some("admin"),
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::JumpCloudScimV2_0,
+                Some("marcelina@foo-corp.com"),
+                None,
+                vec!["Engineering", "All Staff"],
+                None,
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::OktaScimV1_1,
+                Some("marcelina@foo-corp.com"),
+                Some("Software Engineer"),
+                vec!["Engineering"],
+                None,
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::OktaScimV2_0,
+                Some("marcelina@foo-corp.com"),
+                Some("Software Engineer"),
+                vec!["Engineering"],
+                Some("member"),
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::OneLoginScimV2_0,
+                None,
+                None,
+                vec![],
+                None,
+                DirectoryUserState::Suspended,
+            ),
+            (
+                DirectoryType::PeopleHr,
+                Some("marcelina@foo-corp.com"),
+                Some("Developer"),
+                vec!["Engineering"],
+                None,
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::PingFederateScimV2_0,
+                Some("marcelina@foo-corp.com"),
+                Some("Software Engineer"),
+                vec!["Engineering"],
+                None,
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::Rippling,
+                Some("marcelina@foo-corp.com"),
+                Some("Senior Software Engineer"),
+                vec!["Engineering"],
+                None,
+                DirectoryUserState::Active,
+            ),
+            (
+                DirectoryType::Workday,
+                Some("marcelina@foo-corp.com"),
+                Some("Software Engineer II"),
+                vec!["Engineering (Marcelina Davis's Manager)"],
+                None,
+                DirectoryUserState::Inactive,
+            ),
+        ];
+
+        for (directory_type, primary_email, job_title, groups, role, state) in expectations {
+            let directory_user: DirectoryUser = serde_json::from_str(fixture(&directory_type))
+                .unwrap_or_else(|err| panic!("{directory_type:?}: {err}"));
+
+            assert_eq!(
+                directory_user.primary_email(),
+                primary_email,
+                "{directory_type:?}"
+            );
+            assert_eq!(
+                directory_user.job_title.as_deref(),
+                job_title,
+                "{directory_type:?}"
+            );
+            assert_eq!(
+                directory_user
+                    .groups
+                    .iter()
+                    .map(|group| group.name.as_str())
+                    .collect::<Vec<_>>(),
+                groups,
+                "{directory_type:?}"
+            );
+            assert_eq!(
+                directory_user.role.as_ref().map(|role| role.slug.as_str()),
+                role,
+                "{directory_type:?}"
+            );
+            assert_eq!(
+                directory_user.state,
+                KnownOrUnknown::Known(state),
+                "{directory_type:?}"
+            );
+
+            let round_tripped: DirectoryUser =
+                serde_json::from_value(serde_json::to_value(&directory_user).unwrap()).unwrap();
+            assert_eq!(round_tripped, directory_user, "{directory_type:?}");
+        }
+    }
 }
@@ -0,0 +1,218 @@
+use serde::{Deserialize, Serialize};
+
+/// The raw attributes of a [`DirectoryUser`](crate::directory_sync::DirectoryUser) from a
+/// SCIM v2.0 directory.
+///
+/// Decode with [`RawAttributes::decode`](crate::RawAttributes::decode).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ScimUser {
+    /// The ID of the user in the Directory Provider.
+    pub id: Option<String>,
+
+    /// The ID of the user in the provisioning client.
+    pub external_id: Option<String>,
+
+    /// The username of the user.
+    pub user_name: Option<String>,
+
+    /// Whether the user is active.
+    pub active: Option<bool>,
+
+    /// The display name of the user.
+    pub display_name: Option<String>,
+
+    /// The job title of the user.
+    pub title: Option<String>,
+
+    /// The name of the user.
+    pub name: Option<ScimName>,
+
+    /// The email addresses of the user.
+    pub emails: Vec<ScimEmail>,
+
+    /// The attributes from the SCIM enterprise user extension.
+    #[serde(rename = "urn:ietf:params:scim:schemas:extension:enterprise:2.0:User")]
+    pub enterprise: Option<ScimEnterpriseUser>,
+}
+
+/// The raw attributes of a [`DirectoryUser`](crate::directory_sync::DirectoryUser) from an
+/// [Azure AD SCIM v2.0](crate::directory_sync::DirectoryType::AzureScimV2_0) directory.
+pub type AzureScimUser = ScimUser;
+
+/// The raw attributes of a [`DirectoryUser`](crate::directory_sync::DirectoryUser) from an
+/// [Okta SCIM v2.0](crate::directory_sync::DirectoryType::OktaScimV2_0) directory.
+pub type OktaScimUser = ScimUser;
+
+/// The name of a [`ScimUser`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ScimName {
+    /// The full name of the user.
+    pub formatted: Option<String>,
+
+    /// The given name of the user.
+    pub given_name: Option<String>,
+
+    /// The family name of the user.
+    pub family_name: Option<String>,
+}
+
+/// An email address of a [`ScimUser`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScimEmail {
+    /// The email address.
+    pub value: Option<String>,
+
+    /// The type of the email address, e.g., `work`.
+    pub r#type: Option<String>,
+
+    /// Whether this is the user's primary email address.
+    pub primary: Option<bool>,
+}
+
+/// The SCIM enterprise user extension of a [`ScimUser`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ScimEnterpriseUser {
+    /// The employee number of the user.
+    pub employee_number: Option<String>,
+
+    /// The department of the user.
+    pub department: Option<String>,
+
+    /// The manager of the user.
+    pub manager: Option<ScimManager>,
+}
+
+/// The manager of a [`ScimEnterpriseUser`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ScimManager {
+    /// The ID of the manager in the Directory Provider.
+    pub value: Option<String>,
+
+    /// The display name of the manager.
+    pub display_name: Option<String>,
+}
+
+/// The raw attributes of a [`DirectoryUser`](crate::directory_sync::DirectoryUser) from a
+/// [Google Workspace](crate::directory_sync::DirectoryType::GoogleWorkspace) directory.
+///
+/// Decode with [`RawAttributes::decode`](crate::RawAttributes::decode).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GoogleWorkspaceUser {
+    /// The ID of the user in Google Workspace.
+    pub id: Option<String>,
+
+    /// The primary email address of the user.
+    pub primary_email: Option<String>,
+
+    /// The name of the user.
+    pub name: Option<GoogleWorkspaceName>,
+
+    /// Whether the user is suspended.
+    pub suspended: Option<bool>,
+
+    /// Whether the user is a super administrator.
+    pub is_admin: Option<bool>,
+
+    /// The path of the organizational unit the user belongs to.
+    pub org_unit_path: Option<String>,
+}
+
+/// The name of a [`GoogleWorkspaceUser`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GoogleWorkspaceName {
+    /// The full name of the user.
+    pub full_name: Option<String>,
+
+    /// The given name of the user.
+    pub given_name: Option<String>,
+
+    /// The family name of the user.
+    pub family_name: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+    use crate::RawAttributes;
+
+    #[test]
+    fn it_decodes_an_azure_scim_user() {
+        let raw_attributes: RawAttributes = serde_json::from_str(
+            &json!({
+                "schemas": [
+                    "urn:ietf:params:scim:schemas:core:2.0:User",
+                    "urn:ietf:params:scim:schemas:extension:enterprise:2.0:User"
+                ],
+                "externalId": "8c6b0d5b-2f9c-4a4c-9d1f-6c1ed1c6b7a2",
+                "userName": "todd@foo-corp.com",
+                "active": true,
+                "name": {
+                    "givenName": "Todd",
+                    "familyName": "Rundgren"
+                },
+                "emails": [{
+                    "type": "work",
+                    "value": "todd@foo-corp.com",
+                    "primary": true
+                }],
+                "urn:ietf:params:scim:schemas:extension:enterprise:2.0:User": {
+                    "department": "Engineering"
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let user = raw_attributes.decode::<AzureScimUser>().unwrap();
+
+        assert_eq!(user.user_name.as_deref(), Some("todd@foo-corp.com"));
+        assert_eq!(
+            user.name.and_then(|name| name.family_name).as_deref(),
+            Some("Rundgren")
+        );
+        assert_eq!(user.emails[0].primary, Some(true));
+        assert_eq!(
+            user.enterprise,
+            Some(ScimEnterpriseUser {
+                employee_number: None,
+                department: Some("Engineering".to_string()),
+                manager: None,
+            })
+        );
+        assert_eq!(user.title, None);
+    }
+
+    #[test]
+    fn it_decodes_a_google_workspace_user() {
+        let raw_attributes: RawAttributes = serde_json::from_str(
+            &json!({
+                "id": "113356208164934210356",
+                "primaryEmail": "todd@foo-corp.com",
+                "suspended": false,
+                "orgUnitPath": "/Engineering"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            raw_attributes.decode::<GoogleWorkspaceUser>().unwrap(),
+            GoogleWorkspaceUser {
+                id: Some("113356208164934210356".to_string()),
+                primary_email: Some("todd@foo-corp.com".to_string()),
+                suspended: Some(false),
+                org_unit_path: Some("/Engineering".to_string()),
+                ..Default::default()
+            }
+        )
+    }
+}
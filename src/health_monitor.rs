@@ -0,0 +1,434 @@
+//! A module for monitoring the health of SSO connections and directories.
+//!
+//! The [`HealthMonitor`] polls the WorkOS API, which makes it usable as a fallback
+//! for missed `connection.*` and `dsync.*` webhooks.
+
+mod types;
+
+pub use types::*;
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::directory_sync::{
+    Directory, DirectoryId, DirectoryState, ListDirectories, ListDirectoriesParams,
+};
+use crate::sso::{
+    Connection, ConnectionId, ConnectionState, ListConnections, ListConnectionsParams,
+};
+use crate::{paginate_all, KnownOrUnknown, PaginationParams, WorkOs, WorkOsResult};
+
+/// Health monitor for SSO connections and directories.
+///
+/// Each [`poll`](HealthMonitor::poll) lists all connections and directories and compares
+/// their states against the previous poll. The first poll only records a snapshot, and
+/// connections and directories that appear after it are recorded without an event.
+pub struct HealthMonitor<'a> {
+    workos: &'a WorkOs,
+    snapshot: Option<Snapshot>,
+}
+
+#[derive(Debug, Default)]
+struct Snapshot {
+    connections: HashMap<ConnectionId, KnownOrUnknown<ConnectionState, String>>,
+    directories: HashMap<DirectoryId, KnownOrUnknown<DirectoryState, String>>,
+}
+
+impl<'a> HealthMonitor<'a> {
+    /// Returns a new [`HealthMonitor`] instance for the provided WorkOS client.
+    pub fn new(workos: &'a WorkOs) -> Self {
+        Self {
+            workos,
+            snapshot: None,
+        }
+    }
+
+    /// Polls the states of all connections and directories once, and calls `on_event`
+    /// for every change since the previous poll.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos::WorkOsResult;
+    /// # use workos::health_monitor::*;
+    /// use workos::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), ()> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    /// let mut health_monitor = HealthMonitor::new(&workos);
+    ///
+    /// health_monitor
+    ///     .poll(|event| {
+    ///         if let HealthEvent::ConnectionDeactivated { connection } = event {
+    ///             eprintln!("Connection {} was deactivated", connection.id);
+    ///         }
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn poll<F>(&mut self, mut on_event: F) -> WorkOsResult<(), ()>
+    where
+        F: FnMut(HealthEvent),
+    {
+        let connections = self.list_all_connections().await?;
+        let directories = self.list_all_directories().await?;
+
+        let mut snapshot = Snapshot::default();
+        let previous = self.snapshot.take();
+
+        for connection in connections {
+            snapshot
+                .connections
+                .insert(connection.id.clone(), connection.state.clone());
+
+            let previous_state = previous
+                .as_ref()
+                .and_then(|previous| previous.connections.get(&connection.id));
+            if let Some(previous_state) = previous_state {
+                if *previous_state != connection.state {
+                    on_event(HealthEvent::connection_changed(
+                        connection,
+                        previous_state.clone(),
+                    ));
+                }
+            }
+        }
+
+        for directory in directories {
+            snapshot
+                .directories
+                .insert(directory.id.clone(), directory.state.clone());
+
+            let previous_state = previous
+                .as_ref()
+                .and_then(|previous| previous.directories.get(&directory.id));
+            if let Some(previous_state) = previous_state {
+                if *previous_state != directory.state {
+                    on_event(HealthEvent::directory_changed(
+                        directory,
+                        previous_state.clone(),
+                    ));
+                }
+            }
+        }
+
+        if let Some(previous) = previous {
+            for connection_id in previous.connections.into_keys() {
+                if !snapshot.connections.contains_key(&connection_id) {
+                    on_event(HealthEvent::ConnectionDeleted { connection_id });
+                }
+            }
+
+            for directory_id in previous.directories.into_keys() {
+                if !snapshot.directories.contains_key(&directory_id) {
+                    on_event(HealthEvent::DirectoryDeleted { directory_id });
+                }
+            }
+        }
+
+        self.snapshot = Some(snapshot);
+
+        Ok(())
+    }
+
+    /// Polls the states of all connections and directories every `interval`, until a
+    /// poll fails.
+    ///
+    /// See [`poll`](HealthMonitor::poll).
+    pub async fn run<F>(&mut self, interval: Duration, mut on_event: F) -> WorkOsResult<(), ()>
+    where
+        F: FnMut(HealthEvent),
+    {
+        loop {
+            self.poll(&mut on_event).await?;
+
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    async fn list_all_connections(&self) -> WorkOsResult<Vec<Connection>, ()> {
+        paginate_all(|after| async move {
+            self.workos
+                .sso()
+                .list_connections(&ListConnectionsParams {
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await
+        })
+        .await
+    }
+
+    async fn list_all_directories(&self) -> WorkOsResult<Vec<Directory>, ()> {
+        paginate_all(|after| async move {
+            self.workos
+                .directory_sync()
+                .list_directories(&ListDirectoriesParams {
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::ApiKey;
+
+    #[tokio::test]
+    async fn it_emits_events_for_changes_since_the_previous_poll() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _connections_before_mock = server
+            .mock("GET", "/connections")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "connection",
+                      "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                      "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+                      "connection_type": "OktaSAML",
+                      "name": "Foo Corp",
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    },
+                    {
+                      "object": "connection",
+                      "id": "conn_01E2NPPCT7XQ2MVVYDHWGK1WN4",
+                      "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+                      "connection_type": "OktaSAML",
+                      "name": "Foo Corp",
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let _directories_before_mock = server
+            .mock("GET", "/directories")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "domain": "foo-corp.com",
+                      "name": "Foo Corp",
+                      "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                      "object": "directory",
+                      "state": "active",
+                      "type": "gsuite directory",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let _connections_after_mock = server
+            .mock("GET", "/connections")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "connection",
+                      "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                      "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+                      "connection_type": "OktaSAML",
+                      "name": "Foo Corp",
+                      "state": "inactive",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _directories_after_mock = server
+            .mock("GET", "/directories")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                      "domain": "foo-corp.com",
+                      "name": "Foo Corp",
+                      "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                      "object": "directory",
+                      "state": "deleting",
+                      "type": "gsuite directory",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let mut health_monitor = HealthMonitor::new(&workos);
+
+        let mut events = Vec::new();
+        health_monitor
+            .poll(|event| events.push(event))
+            .await
+            .unwrap();
+        assert_eq!(events, vec![]);
+
+        health_monitor
+            .poll(|event| events.push(event))
+            .await
+            .unwrap();
+
+        assert_eq!(events.len(), 3);
+        assert_matches!(
+            &events[0],
+            HealthEvent::ConnectionDeactivated { connection }
+                if connection.id == ConnectionId::from("conn_01E4ZCR3C56J083X43JQXF3JK5")
+        );
+        assert_matches!(
+            &events[1],
+            HealthEvent::DirectoryDeleting { directory }
+                if directory.id == DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74")
+        );
+        assert_eq!(
+            events[2],
+            HealthEvent::ConnectionDeleted {
+                connection_id: ConnectionId::from("conn_01E2NPPCT7XQ2MVVYDHWGK1WN4")
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_lists_all_pages() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let first_page_mock = server
+            .mock("GET", "/connections")
+            .match_query(Matcher::Exact("order=desc".to_string()))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "connection",
+                      "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                      "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+                      "connection_type": "OktaSAML",
+                      "name": "Foo Corp",
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": "conn_01E4ZCR3C56J083X43JQXF3JK5"
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let second_page_mock = server
+            .mock("GET", "/connections")
+            .match_query(Matcher::UrlEncoded(
+                "after".to_string(),
+                "conn_01E4ZCR3C56J083X43JQXF3JK5".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "connection",
+                      "id": "conn_01E2NPPCT7XQ2MVVYDHWGK1WN4",
+                      "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+                      "connection_type": "OktaSAML",
+                      "name": "Foo Corp",
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let health_monitor = HealthMonitor::new(&workos);
+        let connections = health_monitor.list_all_connections().await.unwrap();
+
+        first_page_mock.assert_async().await;
+        second_page_mock.assert_async().await;
+        assert_eq!(connections.len(), 2);
+    }
+}
@@ -0,0 +1,3 @@
+mod health_event;
+
+pub use health_event::*;
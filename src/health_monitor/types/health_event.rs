@@ -0,0 +1,127 @@
+use crate::directory_sync::{Directory, DirectoryId, DirectoryState};
+use crate::sso::{Connection, ConnectionId, ConnectionState};
+use crate::KnownOrUnknown;
+
+/// A change in the health of a [`Connection`] or [`Directory`], emitted by a
+/// [`HealthMonitor`](crate::health_monitor::HealthMonitor).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthEvent {
+    /// A connection became active.
+    ConnectionActivated {
+        /// The connection.
+        connection: Connection,
+    },
+
+    /// A connection became inactive.
+    ConnectionDeactivated {
+        /// The connection.
+        connection: Connection,
+    },
+
+    /// A connection changed to a state not known to this SDK.
+    ConnectionStateChanged {
+        /// The connection.
+        connection: Connection,
+
+        /// The previous state of the connection.
+        previous_state: KnownOrUnknown<ConnectionState, String>,
+    },
+
+    /// A connection was deleted.
+    ConnectionDeleted {
+        /// The ID of the deleted connection.
+        connection_id: ConnectionId,
+    },
+
+    /// A directory became active.
+    DirectoryActivated {
+        /// The directory.
+        directory: Directory,
+    },
+
+    /// A directory became inactive.
+    DirectoryDeactivated {
+        /// The directory.
+        directory: Directory,
+    },
+
+    /// A directory is being validated.
+    DirectoryValidating {
+        /// The directory.
+        directory: Directory,
+    },
+
+    /// A directory's credentials became invalid.
+    DirectoryInvalidCredentials {
+        /// The directory.
+        directory: Directory,
+    },
+
+    /// A directory is being deleted.
+    DirectoryDeleting {
+        /// The directory.
+        directory: Directory,
+    },
+
+    /// A directory changed to a state not known to this SDK.
+    DirectoryStateChanged {
+        /// The directory.
+        directory: Directory,
+
+        /// The previous state of the directory.
+        previous_state: KnownOrUnknown<DirectoryState, String>,
+    },
+
+    /// A directory was deleted.
+    DirectoryDeleted {
+        /// The ID of the deleted directory.
+        directory_id: DirectoryId,
+    },
+}
+
+impl HealthEvent {
+    pub(crate) fn connection_changed(
+        connection: Connection,
+        previous_state: KnownOrUnknown<ConnectionState, String>,
+    ) -> Self {
+        match connection.state {
+            KnownOrUnknown::Known(ConnectionState::Active) => {
+                HealthEvent::ConnectionActivated { connection }
+            }
+            KnownOrUnknown::Known(ConnectionState::Inactive) => {
+                HealthEvent::ConnectionDeactivated { connection }
+            }
+            KnownOrUnknown::Unknown(_) => HealthEvent::ConnectionStateChanged {
+                connection,
+                previous_state,
+            },
+        }
+    }
+
+    pub(crate) fn directory_changed(
+        directory: Directory,
+        previous_state: KnownOrUnknown<DirectoryState, String>,
+    ) -> Self {
+        match directory.state {
+            KnownOrUnknown::Known(DirectoryState::Active) => {
+                HealthEvent::DirectoryActivated { directory }
+            }
+            KnownOrUnknown::Known(DirectoryState::Inactive) => {
+                HealthEvent::DirectoryDeactivated { directory }
+            }
+            KnownOrUnknown::Known(DirectoryState::Validating) => {
+                HealthEvent::DirectoryValidating { directory }
+            }
+            KnownOrUnknown::Known(DirectoryState::InvalidCredentials) => {
+                HealthEvent::DirectoryInvalidCredentials { directory }
+            }
+            KnownOrUnknown::Known(DirectoryState::Deleting) => {
+                HealthEvent::DirectoryDeleting { directory }
+            }
+            KnownOrUnknown::Unknown(_) => HealthEvent::DirectoryStateChanged {
+                directory,
+                previous_state,
+            },
+        }
+    }
+}
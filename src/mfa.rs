@@ -2,9 +2,11 @@
 //!
 //! [WorkOS Docs: MFA Guide](https://workos.com/docs/mfa/guide)
 
+mod lockout;
 mod operations;
 mod types;
 
+pub use lockout::*;
 pub use operations::*;
 pub use types::*;
 
@@ -0,0 +1,481 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use thiserror::Error;
+
+use crate::mfa::{
+    AuthenticationFactorId, GetChallenge, GetChallengeError, Mfa, VerifyChallenge,
+    VerifyChallengeError, VerifyChallengeParams, VerifyChallengeResponse,
+};
+use crate::{WorkOsError, WorkOsResult};
+
+/// A store that counts verification attempts per authentication factor, so that an
+/// [`MfaLockout`] can lock a factor out after too many attempts.
+pub trait AttemptCounterStore: Send + Sync {
+    /// Records an attempt for the factor and keeps the count until `expires_at`.
+    ///
+    /// Returns the number of attempts, including this one. The increment must be atomic,
+    /// since concurrent attempts rely on it to reserve their place.
+    fn increment(&self, factor_id: &AuthenticationFactorId, expires_at: DateTime<Utc>) -> u32;
+
+    /// Returns the number of attempts for the factor that have not yet expired.
+    fn get(&self, factor_id: &AuthenticationFactorId) -> u32;
+
+    /// Clears the attempts for the factor.
+    fn reset(&self, factor_id: &AuthenticationFactorId);
+}
+
+/// An [`AttemptCounterStore`] that keeps attempt counts in memory.
+///
+/// Counts are forgotten once they expire. When running more than one instance of a
+/// service, use a shared store instead.
+#[derive(Debug, Default)]
+pub struct InMemoryAttemptCounterStore {
+    attempts: Mutex<BTreeMap<AuthenticationFactorId, (u32, DateTime<Utc>)>>,
+}
+
+impl AttemptCounterStore for InMemoryAttemptCounterStore {
+    fn increment(&self, factor_id: &AuthenticationFactorId, expires_at: DateTime<Utc>) -> u32 {
+        let mut attempts = self.attempts.lock().unwrap_or_else(|err| err.into_inner());
+
+        let now = Utc::now();
+        attempts.retain(|_, (_, expires_at)| *expires_at > now);
+
+        let entry = attempts.entry(factor_id.clone()).or_insert((0, expires_at));
+        entry.0 += 1;
+        entry.1 = expires_at;
+
+        entry.0
+    }
+
+    fn get(&self, factor_id: &AuthenticationFactorId) -> u32 {
+        let attempts = self.attempts.lock().unwrap_or_else(|err| err.into_inner());
+
+        match attempts.get(factor_id) {
+            Some((count, expires_at)) if *expires_at > Utc::now() => *count,
+            _ => 0,
+        }
+    }
+
+    fn reset(&self, factor_id: &AuthenticationFactorId) {
+        let mut attempts = self.attempts.lock().unwrap_or_else(|err| err.into_inner());
+
+        attempts.remove(factor_id);
+    }
+}
+
+/// An error returned from [`MfaLockout::verify_challenge`].
+#[derive(Debug, Error)]
+pub enum MfaLockoutError {
+    /// The factor has too many failed attempts and is locked out.
+    #[error("authentication factor is locked out")]
+    LockedOut,
+
+    /// The challenge was issued for a different authentication factor than the one
+    /// provided.
+    #[error("authentication challenge belongs to a different authentication factor")]
+    FactorMismatch,
+
+    /// The challenge could not be retrieved.
+    #[error(transparent)]
+    GetChallenge(#[from] GetChallengeError),
+
+    /// The challenge could not be verified.
+    #[error(transparent)]
+    VerifyChallenge(#[from] VerifyChallengeError),
+}
+
+impl From<MfaLockoutError> for WorkOsError<MfaLockoutError> {
+    fn from(err: MfaLockoutError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+/// Locks an authentication factor out after too many failed verification attempts.
+///
+/// The challenge is retrieved first, so that attempts are always counted against the
+/// factor it was issued for. Every attempt is counted before its code is verified, so
+/// that concurrent attempts
+/// cannot get past `max_attempts` between checking and counting. Once a factor reaches
+/// `max_attempts`, it stays locked until no further attempts are recorded for the
+/// lockout duration. A successful verification clears the count.
+///
+/// # Examples
+///
+/// ```
+/// # use workos::WorkOsResult;
+/// # use workos::mfa::*;
+/// use workos::{ApiKey, WorkOs};
+///
+/// # async fn run() -> WorkOsResult<(), MfaLockoutError> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+/// let lockout = MfaLockout::new(InMemoryAttemptCounterStore::default(), 5);
+///
+/// let response = lockout
+///     .verify_challenge(
+///         &workos.mfa(),
+///         &AuthenticationFactorId::from("auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ"),
+///         &VerifyChallengeParams {
+///             authentication_challenge_id: &AuthenticationChallengeId::from(
+///                 "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+///             ),
+///             code: &MfaCode::from("123456"),
+///         },
+///     )
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MfaLockout<S> {
+    store: S,
+    max_attempts: u32,
+    lockout_duration: Duration,
+}
+
+impl<S: AttemptCounterStore> MfaLockout<S> {
+    /// Returns a new [`MfaLockout`] that locks a factor out after `max_attempts` failed
+    /// attempts.
+    ///
+    /// Factors stay locked for 15 minutes by default.
+    pub fn new(store: S, max_attempts: u32) -> Self {
+        Self {
+            store,
+            max_attempts,
+            lockout_duration: Duration::minutes(15),
+        }
+    }
+
+    /// Sets how long failed attempts are remembered after the most recent failure.
+    pub fn with_lockout_duration(mut self, lockout_duration: Duration) -> Self {
+        self.lockout_duration = lockout_duration;
+        self
+    }
+
+    /// Returns whether the factor is currently locked out.
+    pub fn is_locked_out(&self, factor_id: &AuthenticationFactorId) -> bool {
+        self.store.get(factor_id) >= self.max_attempts
+    }
+
+    /// Records a failed attempt for the factor.
+    ///
+    /// Returns `true` if the factor is now locked out.
+    pub fn record_failure(&self, factor_id: &AuthenticationFactorId) -> bool {
+        let expires_at = Utc::now() + self.lockout_duration;
+
+        self.store.increment(factor_id, expires_at) >= self.max_attempts
+    }
+
+    /// Clears the failed attempts for the factor.
+    pub fn reset(&self, factor_id: &AuthenticationFactorId) {
+        self.store.reset(factor_id)
+    }
+
+    /// Verifies a challenge for the factor, unless the factor is locked out.
+    ///
+    /// The challenge must have been issued for `factor_id`; otherwise
+    /// [`MfaLockoutError::FactorMismatch`] is returned without verifying the code. The
+    /// attempt is counted before the challenge is verified and a valid code clears the
+    /// count.
+    pub async fn verify_challenge(
+        &self,
+        mfa: &Mfa<'_>,
+        factor_id: &AuthenticationFactorId,
+        params: &VerifyChallengeParams<'_>,
+    ) -> WorkOsResult<VerifyChallengeResponse, MfaLockoutError> {
+        let challenge = mfa
+            .get_challenge(params.authentication_challenge_id)
+            .await
+            .map_err(|err| err.map_operation(MfaLockoutError::GetChallenge))?;
+
+        if challenge.authentication_factor_id != *factor_id {
+            return Err(MfaLockoutError::FactorMismatch.into());
+        }
+
+        let expires_at = Utc::now() + self.lockout_duration;
+        if self.store.increment(factor_id, expires_at) > self.max_attempts {
+            return Err(MfaLockoutError::LockedOut.into());
+        }
+
+        let response = mfa
+            .verify_challenge(params)
+            .await
+            .map_err(|err| err.map_operation(MfaLockoutError::VerifyChallenge))?;
+
+        if response.is_valid {
+            self.reset(factor_id);
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures_util::future::join_all;
+    use matches::assert_matches;
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::mfa::{AuthenticationChallengeId, MfaCode};
+    use crate::{ApiKey, WorkOs};
+
+    #[test]
+    fn it_locks_a_factor_out_after_too_many_failures() {
+        let lockout = MfaLockout::new(InMemoryAttemptCounterStore::default(), 3);
+        let factor_id = AuthenticationFactorId::from("auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ");
+        let other_factor_id =
+            AuthenticationFactorId::from("auth_factor_01FXNWW32G7F3MG8MYK5D1HJJM");
+
+        assert!(!lockout.record_failure(&factor_id));
+        assert!(!lockout.record_failure(&factor_id));
+        assert!(!lockout.is_locked_out(&factor_id));
+        assert!(lockout.record_failure(&factor_id));
+        assert!(lockout.is_locked_out(&factor_id));
+        assert!(!lockout.is_locked_out(&other_factor_id));
+
+        lockout.reset(&factor_id);
+
+        assert!(!lockout.is_locked_out(&factor_id));
+    }
+
+    #[test]
+    fn it_forgets_failures_once_they_expire() {
+        let lockout = MfaLockout::new(InMemoryAttemptCounterStore::default(), 1)
+            .with_lockout_duration(Duration::seconds(-1));
+        let factor_id = AuthenticationFactorId::from("auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ");
+
+        lockout.record_failure(&factor_id);
+
+        assert!(!lockout.is_locked_out(&factor_id));
+    }
+
+    #[tokio::test]
+    async fn it_records_invalid_codes_and_rejects_locked_out_factors() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let get_mock = server
+            .mock(
+                "GET",
+                "/auth/challenges/auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+            )
+            .with_status(200)
+            .with_body(
+                json!({
+                  "object": "authentication_challenge",
+                  "id": "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+                  "created_at": "2022-02-15T15:26:53.274Z",
+                  "updated_at": "2022-02-15T15:26:53.274Z",
+                  "expires_at": "2022-02-15T15:36:53.279Z",
+                  "authentication_factor_id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ"
+                })
+                .to_string(),
+            )
+            .expect(3)
+            .create_async()
+            .await;
+
+        let mock = server
+            .mock(
+                "POST",
+                "/auth/challenges/auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5/verify",
+            )
+            .with_status(201)
+            .with_body(
+                json!({
+                  "challenge": {
+                    "object": "authentication_challenge",
+                    "id": "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+                    "created_at": "2022-02-15T15:26:53.274Z",
+                    "updated_at": "2022-02-15T15:26:53.274Z",
+                    "expires_at": "2022-02-15T15:36:53.279Z",
+                    "authentication_factor_id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ"
+                  },
+                  "valid": false
+                })
+                .to_string(),
+            )
+            .expect(2)
+            .create_async()
+            .await;
+
+        let lockout = MfaLockout::new(InMemoryAttemptCounterStore::default(), 2);
+        let factor_id = AuthenticationFactorId::from("auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ");
+        let params = VerifyChallengeParams {
+            authentication_challenge_id: &AuthenticationChallengeId::from(
+                "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+            ),
+            code: &MfaCode::from("000000"),
+        };
+
+        for _ in 0..2 {
+            let response = lockout
+                .verify_challenge(&workos.mfa(), &factor_id, &params)
+                .await
+                .unwrap();
+
+            assert!(!response.is_valid);
+        }
+
+        let result = lockout
+            .verify_challenge(&workos.mfa(), &factor_id, &params)
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(MfaLockoutError::LockedOut))
+        );
+        get_mock.assert_async().await;
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_does_not_let_concurrent_attempts_exceed_the_maximum() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let get_mock = server
+            .mock(
+                "GET",
+                "/auth/challenges/auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+            )
+            .with_status(200)
+            .with_body(
+                json!({
+                  "object": "authentication_challenge",
+                  "id": "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+                  "created_at": "2022-02-15T15:26:53.274Z",
+                  "updated_at": "2022-02-15T15:26:53.274Z",
+                  "expires_at": "2022-02-15T15:36:53.279Z",
+                  "authentication_factor_id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ"
+                })
+                .to_string(),
+            )
+            .expect(5)
+            .create_async()
+            .await;
+
+        let mock = server
+            .mock(
+                "POST",
+                "/auth/challenges/auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5/verify",
+            )
+            .with_status(201)
+            .with_body(
+                json!({
+                  "challenge": {
+                    "object": "authentication_challenge",
+                    "id": "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+                    "created_at": "2022-02-15T15:26:53.274Z",
+                    "updated_at": "2022-02-15T15:26:53.274Z",
+                    "expires_at": "2022-02-15T15:36:53.279Z",
+                    "authentication_factor_id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ"
+                  },
+                  "valid": false
+                })
+                .to_string(),
+            )
+            .expect(2)
+            .create_async()
+            .await;
+
+        let lockout = MfaLockout::new(InMemoryAttemptCounterStore::default(), 2);
+        let factor_id = AuthenticationFactorId::from("auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ");
+        let params = VerifyChallengeParams {
+            authentication_challenge_id: &AuthenticationChallengeId::from(
+                "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+            ),
+            code: &MfaCode::from("000000"),
+        };
+
+        let mfa = workos.mfa();
+        let results =
+            join_all((0..5).map(|_| lockout.verify_challenge(&mfa, &factor_id, &params))).await;
+
+        assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 2);
+        assert_eq!(
+            results
+                .iter()
+                .filter(|result| matches!(
+                    result,
+                    Err(WorkOsError::Operation(MfaLockoutError::LockedOut))
+                ))
+                .count(),
+            3
+        );
+        get_mock.assert_async().await;
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_challenge_for_a_different_factor() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let get_mock = server
+            .mock(
+                "GET",
+                "/auth/challenges/auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+            )
+            .with_status(200)
+            .with_body(
+                json!({
+                  "object": "authentication_challenge",
+                  "id": "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+                  "created_at": "2022-02-15T15:26:53.274Z",
+                  "updated_at": "2022-02-15T15:26:53.274Z",
+                  "expires_at": "2022-02-15T15:36:53.279Z",
+                  "authentication_factor_id": "auth_factor_01FXNWW32G7F3MG8MYK5D1HJJM"
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let verify_mock = server
+            .mock(
+                "POST",
+                "/auth/challenges/auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5/verify",
+            )
+            .expect(0)
+            .create_async()
+            .await;
+
+        let lockout = MfaLockout::new(InMemoryAttemptCounterStore::default(), 1);
+        let factor_id = AuthenticationFactorId::from("auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ");
+
+        let result = lockout
+            .verify_challenge(
+                &workos.mfa(),
+                &factor_id,
+                &VerifyChallengeParams {
+                    authentication_challenge_id: &AuthenticationChallengeId::from(
+                        "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+                    ),
+                    code: &MfaCode::from("000000"),
+                },
+            )
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(MfaLockoutError::FactorMismatch))
+        );
+        assert!(!lockout.is_locked_out(&factor_id));
+        get_mock.assert_async().await;
+        verify_mock.assert_async().await;
+    }
+}
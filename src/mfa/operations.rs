@@ -1,7 +1,13 @@
 mod challenge_factor;
+mod delete_factor;
 mod enroll_factor;
+mod get_challenge;
+mod get_factor;
 mod verify_challenge;
 
 pub use challenge_factor::*;
+pub use delete_factor::*;
 pub use enroll_factor::*;
+pub use get_challenge::*;
+pub use get_factor::*;
 pub use verify_challenge::*;
@@ -1,9 +1,10 @@
 use async_trait::async_trait;
+use reqwest::{Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::mfa::{AuthenticationChallenge, AuthenticationChallengeId, Mfa, MfaCode};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{ResponseExt, WorkOsError, WorkOsResult};
 
 /// The response for [`VerifyChallenge`].
 #[derive(Debug, Serialize, Deserialize)]
@@ -29,7 +30,70 @@ pub struct VerifyChallengeParams<'a> {
 
 /// An error returned from [`VerifyChallenge`].
 #[derive(Debug, Error)]
-pub enum VerifyChallengeError {}
+pub enum VerifyChallengeError {
+    /// The authentication challenge has expired.
+    #[error("authentication challenge expired: {message}")]
+    Expired {
+        /// The error message returned from the API.
+        message: String,
+    },
+
+    /// The authentication challenge was already verified.
+    #[error("authentication challenge already verified: {message}")]
+    AlreadyVerified {
+        /// The error message returned from the API.
+        message: String,
+    },
+}
+
+impl From<VerifyChallengeError> for WorkOsError<VerifyChallengeError> {
+    fn from(err: VerifyChallengeError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkOsApiError {
+    pub code: String,
+    pub message: String,
+}
+
+#[async_trait]
+trait HandleVerifyChallengeError
+where
+    Self: Sized,
+{
+    async fn handle_verify_challenge_error(self) -> WorkOsResult<Self, VerifyChallengeError>;
+}
+
+#[async_trait]
+impl HandleVerifyChallengeError for Response {
+    async fn handle_verify_challenge_error(self) -> WorkOsResult<Self, VerifyChallengeError> {
+        match self.error_for_status_ref() {
+            Ok(_) => Ok(self),
+            Err(err) => match err.status() {
+                Some(StatusCode::UNPROCESSABLE_ENTITY) => {
+                    let error = self.json::<WorkOsApiError>().await?;
+
+                    Err(match error.code.as_str() {
+                        "authentication_challenge_expired" => {
+                            WorkOsError::Operation(VerifyChallengeError::Expired {
+                                message: error.message,
+                            })
+                        }
+                        "authentication_challenge_previously_verified" => {
+                            WorkOsError::Operation(VerifyChallengeError::AlreadyVerified {
+                                message: error.message,
+                            })
+                        }
+                        _ => WorkOsError::RequestError(err),
+                    })
+                }
+                _ => Err(WorkOsError::RequestError(err)),
+            },
+        }
+    }
+}
 
 /// [WorkOS Docs: Verify Challenge](https://workos.com/docs/reference/mfa/verify-challenge)
 #[async_trait]
@@ -84,7 +148,9 @@ impl VerifyChallenge for Mfa<'_> {
             .json(&params)
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()?
+            .handle_unauthorized_error()?
+            .handle_verify_challenge_error()
+            .await?
             .json::<VerifyChallengeResponse>()
             .await?;
 
@@ -94,7 +160,7 @@ impl VerifyChallenge for Mfa<'_> {
 
 #[cfg(test)]
 mod test {
-
+    use matches::assert_matches;
     use serde_json::json;
     use tokio;
 
@@ -151,4 +217,88 @@ mod test {
             AuthenticationChallengeId::from("auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5")
         )
     }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_the_challenge_has_expired() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock(
+                "POST",
+                "/auth/challenges/auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5/verify",
+            )
+            .with_status(422)
+            .with_body(
+                json!({
+                    "message": "The authentication challenge 'auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5' has expired.",
+                    "code": "authentication_challenge_expired"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let result = workos
+            .mfa()
+            .verify_challenge(&VerifyChallengeParams {
+                authentication_challenge_id: &AuthenticationChallengeId::from(
+                    "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+                ),
+                code: &MfaCode::from("123456"),
+            })
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(VerifyChallengeError::Expired {
+                message: _
+            }))
+        )
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_the_challenge_was_already_verified() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock(
+                "POST",
+                "/auth/challenges/auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5/verify",
+            )
+            .with_status(422)
+            .with_body(
+                json!({
+                    "message": "The authentication challenge 'auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5' has already been verified.",
+                    "code": "authentication_challenge_previously_verified"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let result = workos
+            .mfa()
+            .verify_challenge(&VerifyChallengeParams {
+                authentication_challenge_id: &AuthenticationChallengeId::from(
+                    "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+                ),
+                code: &MfaCode::from("123456"),
+            })
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(
+                VerifyChallengeError::AlreadyVerified { message: _ }
+            ))
+        )
+    }
 }
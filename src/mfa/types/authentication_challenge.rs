@@ -41,6 +41,11 @@ pub struct AuthenticationChallenge {
     /// This will always be [`None`] for time-based one-time password (TOTP) factors.
     pub expires_at: Option<Timestamp>,
 
+    /// Whether the authentication challenge was already verified.
+    ///
+    /// This is `None` when the response does not include it.
+    pub verified: Option<bool>,
+
     /// The timestamps for the authentication challenge.
     #[serde(flatten)]
     pub timestamps: Timestamps,
@@ -76,6 +81,7 @@ mod test {
                     "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ"
                 ),
                 expires_at: Timestamp::try_from("2022-02-15T15:36:53.279Z").ok(),
+                verified: None,
                 timestamps: Timestamps {
                     created_at: Timestamp::try_from("2022-02-15T15:26:53.274Z").unwrap(),
                     updated_at: Timestamp::try_from("2022-02-15T15:26:53.274Z").unwrap(),
@@ -83,4 +89,23 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn it_deserializes_a_verified_authentication_challenge() {
+        let challenge: AuthenticationChallenge = serde_json::from_str(
+            &json!({
+              "object": "authentication_challenge",
+              "id": "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+              "authentication_factor_id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ",
+              "expires_at": null,
+              "verified": true,
+              "created_at": "2022-02-15T15:26:53.274Z",
+              "updated_at": "2022-02-15T15:26:53.274Z"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(challenge.verified, Some(true))
+    }
 }
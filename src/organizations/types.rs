@@ -1,3 +1,5 @@
+mod domain_policy;
 mod organization;
 
+pub use domain_policy::*;
 pub use organization::*;
@@ -0,0 +1,240 @@
+use thiserror::Error;
+
+use crate::organizations::{Organization, OrganizationId};
+use crate::sso::Profile;
+
+/// A violation of an organization's email-domain policy, returned from
+/// [`DomainPolicy::check`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DomainPolicyViolation {
+    /// The profile belongs to a different organization.
+    #[error("profile belongs to organization {actual:?}, expected {expected}")]
+    OrganizationMismatch {
+        /// The ID of the organization the profile was checked against.
+        expected: OrganizationId,
+
+        /// The ID of the organization the profile belongs to.
+        actual: Option<OrganizationId>,
+    },
+
+    /// The profile's email address has no domain.
+    #[error("invalid email address '{email}'")]
+    InvalidEmail {
+        /// The email address of the profile.
+        email: String,
+    },
+
+    /// The domain of the profile's email address is not a domain of the organization.
+    #[error("email domain '{domain}' is not allowed for the organization")]
+    DomainNotAllowed {
+        /// The domain of the profile's email address.
+        domain: String,
+    },
+}
+
+/// The email-domain policy of an [`Organization`].
+///
+/// WorkOS enforces this policy itself for most connection types. This check is for
+/// code that provisions users from a [`Profile`], e.g., just-in-time provisioning, on
+/// connections that do not.
+///
+/// [WorkOS Docs: Allow profiles outside organization](https://workos.com/docs/sso/guide/frequently-asked-questions#allow-profiles-outside-organization)
+#[derive(Debug)]
+pub struct DomainPolicy;
+
+impl DomainPolicy {
+    /// Checks a profile against the email-domain policy of an organization.
+    ///
+    /// The profile must belong to the organization. Unless the organization allows
+    /// profiles outside the organization, the domain of the profile's email address must
+    /// match one of the organization's domains, or be a subdomain of one. Domains are
+    /// compared case-insensitively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos::organizations::*;
+    /// # use workos::sso::Profile;
+    /// # fn run(organization: &Organization, profile: &Profile) {
+    /// match DomainPolicy::check(organization, profile) {
+    ///     Ok(()) => { /* provision the user */ }
+    ///     Err(violation) => eprintln!("rejected profile: {violation}"),
+    /// }
+    /// # }
+    /// ```
+    pub fn check<TCustomAttributes>(
+        organization: &Organization,
+        profile: &Profile<TCustomAttributes>,
+    ) -> Result<(), DomainPolicyViolation> {
+        if profile.organization_id.as_ref() != Some(&organization.id) {
+            return Err(DomainPolicyViolation::OrganizationMismatch {
+                expected: organization.id.clone(),
+                actual: profile.organization_id.clone(),
+            });
+        }
+
+        let domain =
+            email_domain(&profile.email).ok_or_else(|| DomainPolicyViolation::InvalidEmail {
+                email: profile.email.clone(),
+            })?;
+
+        if organization.allow_profiles_outside_organization
+            || is_organization_domain(organization, domain)
+        {
+            Ok(())
+        } else {
+            Err(DomainPolicyViolation::DomainNotAllowed {
+                domain: domain.to_lowercase(),
+            })
+        }
+    }
+
+    /// Returns whether the profile's email address can be trusted as verified for the
+    /// organization.
+    ///
+    /// This is the case when the profile belongs to the organization and its email
+    /// domain is one of the organization's domains, or a subdomain of one, regardless of
+    /// whether the organization allows profiles outside the organization.
+    pub fn is_email_verified<TCustomAttributes>(
+        organization: &Organization,
+        profile: &Profile<TCustomAttributes>,
+    ) -> bool {
+        profile.organization_id.as_ref() == Some(&organization.id)
+            && email_domain(&profile.email)
+                .is_some_and(|domain| is_organization_domain(organization, domain))
+    }
+}
+
+fn email_domain(email: &str) -> Option<&str> {
+    match email.rsplit_once('@') {
+        Some((_, domain)) if !domain.is_empty() => Some(domain.trim_end_matches('.')),
+        _ => None,
+    }
+}
+
+fn is_organization_domain(organization: &Organization, domain: &str) -> bool {
+    organization
+        .domains
+        .iter()
+        .any(|organization_domain| is_same_or_subdomain(domain, &organization_domain.domain))
+}
+
+fn is_same_or_subdomain(domain: &str, parent: &str) -> bool {
+    let domain = domain.to_lowercase();
+    let parent = parent.trim_end_matches('.').to_lowercase();
+
+    domain == parent
+        || domain
+            .strip_suffix(&parent)
+            .is_some_and(|subdomain| subdomain.ends_with('.'))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use matches::assert_matches;
+
+    use super::*;
+    use crate::organizations::{OrganizationDomain, OrganizationDomainId};
+    use crate::sso::{ConnectionId, ConnectionType, ProfileId};
+    use crate::{KnownOrUnknown, RawAttributes, Timestamp, Timestamps};
+
+    fn organization(allow_profiles_outside_organization: bool) -> Organization {
+        Organization {
+            id: OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"),
+            name: "Foo Corp".to_string(),
+            allow_profiles_outside_organization,
+            domains: vec![OrganizationDomain {
+                id: OrganizationDomainId::from("org_domain_01EHZNVPK2QXHMVWCEDQEKY69A"),
+                domain: "foo-corp.com".to_string(),
+            }],
+            timestamps: Timestamps {
+                created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+            },
+        }
+    }
+
+    fn profile(email: &str) -> Profile {
+        Profile {
+            id: ProfileId::from("prof_01DMC79VCBZ0NY2099737PSVF1"),
+            connection_id: ConnectionId::from("conn_01E4ZCR3C56J083X43JQXF3JK5"),
+            organization_id: Some(OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")),
+            connection_type: KnownOrUnknown::Known(ConnectionType::OktaSaml),
+            idp_id: "00u1a0ufowBJlzPlk357".to_string(),
+            email: email.to_string(),
+            first_name: None,
+            last_name: None,
+            role: None,
+            groups: None,
+            custom_attributes: HashMap::new(),
+            raw_attributes: RawAttributes(HashMap::new()),
+        }
+    }
+
+    #[test]
+    fn it_allows_a_profile_with_a_matching_domain() {
+        assert_eq!(
+            DomainPolicy::check(&organization(false), &profile("todd@Foo-Corp.COM")),
+            Ok(())
+        )
+    }
+
+    #[test]
+    fn it_allows_a_profile_with_a_subdomain() {
+        assert_eq!(
+            DomainPolicy::check(&organization(false), &profile("todd@eu.foo-corp.com")),
+            Ok(())
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_profile_with_a_lookalike_domain() {
+        assert_eq!(
+            DomainPolicy::check(&organization(false), &profile("todd@evil-foo-corp.com")),
+            Err(DomainPolicyViolation::DomainNotAllowed {
+                domain: "evil-foo-corp.com".to_string()
+            })
+        )
+    }
+
+    #[test]
+    fn it_allows_any_domain_when_profiles_outside_the_organization_are_allowed() {
+        assert_eq!(
+            DomainPolicy::check(&organization(true), &profile("todd@example.com")),
+            Ok(())
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_profile_from_another_organization() {
+        let mut profile = profile("todd@foo-corp.com");
+        profile.organization_id = Some(OrganizationId::from("org_01EHWNCE74X7JSDV0X3SZ3KJNY"));
+
+        assert_matches!(
+            DomainPolicy::check(&organization(true), &profile),
+            Err(DomainPolicyViolation::OrganizationMismatch { .. })
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_profile_with_an_invalid_email() {
+        assert_matches!(
+            DomainPolicy::check(&organization(false), &profile("todd")),
+            Err(DomainPolicyViolation::InvalidEmail { .. })
+        )
+    }
+
+    #[test]
+    fn it_only_trusts_emails_on_the_organization_domains_as_verified() {
+        assert!(DomainPolicy::is_email_verified(
+            &organization(true),
+            &profile("todd@eu.foo-corp.com")
+        ));
+        assert!(!DomainPolicy::is_email_verified(
+            &organization(true),
+            &profile("todd@example.com")
+        ));
+    }
+}
@@ -4,6 +4,7 @@ mod get_connection;
 mod get_profile;
 mod get_profile_and_token;
 mod list_connections;
+mod route_by_email;
 
 pub use delete_connection::*;
 pub use get_authorization_url::*;
@@ -11,3 +12,4 @@ pub use get_connection::*;
 pub use get_profile::*;
 pub use get_profile_and_token::*;
 pub use list_connections::*;
+pub use route_by_email::*;
@@ -1,16 +1,47 @@
 use url::{ParseError, Url};
 
 use crate::organizations::OrganizationId;
-use crate::sso::{ClientId, ConnectionId, Sso};
+use crate::sso::{ClientId, CodeChallenge, ConnectionId, Sso};
+use crate::KnownOrUnknown;
 
 /// An OAuth provider to use for Single Sign-On (SSO).
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Provider {
+    /// Sign in with Apple OAuth.
+    AppleOauth,
+
+    /// Sign in with GitHub OAuth.
+    GitHubOauth,
+
+    /// Sign in with GitLab OAuth.
+    GitLabOauth,
+
     /// Sign in with Google OAuth.
     GoogleOauth,
 
     /// Sign in with Microsoft OAuth.
     MicrosoftOauth,
+
+    /// Sign in with Salesforce OAuth.
+    SalesforceOauth,
+
+    /// Sign in with Slack OAuth.
+    SlackOauth,
+}
+
+impl Provider {
+    /// Returns the value of the `provider` parameter for this provider.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Provider::AppleOauth => "AppleOAuth",
+            Provider::GitHubOauth => "GitHubOAuth",
+            Provider::GitLabOauth => "GitLabOAuth",
+            Provider::GoogleOauth => "GoogleOAuth",
+            Provider::MicrosoftOauth => "MicrosoftOAuth",
+            Provider::SalesforceOauth => "SalesforceOAuth",
+            Provider::SlackOauth => "SlackOAuth",
+        }
+    }
 }
 
 /// The selector to use to determine which connection to use for SSO.
@@ -23,7 +54,10 @@ pub enum ConnectionSelector<'a> {
     Organization(&'a OrganizationId),
 
     /// Initiate SSO for the specified OAuth provider.
-    Provider(&'a Provider),
+    ///
+    /// Providers not yet known to this SDK can be passed as
+    /// [`Unknown`](KnownOrUnknown::Unknown) using their WorkOS name, e.g., `"SomeNewOAuth"`.
+    Provider(KnownOrUnknown<&'a Provider, &'a str>),
 }
 
 /// The parameters for [`GetAuthorizationUrl`].
@@ -35,13 +69,28 @@ pub struct GetAuthorizationUrlParams<'a> {
     pub client_id: &'a ClientId,
 
     /// The redirect URI the user will be redirected to after successfully signing in.
-    pub redirect_uri: &'a str,
+    pub redirect_uri: &'a Url,
 
     /// The connection selector to use to initiate SSO.
     pub connection_selector: ConnectionSelector<'a>,
 
     /// The state parameter that will be passed back to the redirect URI.
+    ///
+    /// Use a [`StateCodec`](crate::sso::StateCodec) to sign and verify it.
     pub state: Option<&'a str>,
+
+    /// A domain to pre-fill on the IdP's sign-in page, e.g., for Google OAuth.
+    pub domain_hint: Option<&'a str>,
+
+    /// An email address or username to pre-fill on the IdP's sign-in page.
+    pub login_hint: Option<&'a str>,
+
+    /// Additional OAuth scopes to request from the provider, when using an OAuth provider.
+    pub provider_scopes: Option<&'a [&'a str]>,
+
+    /// The `S256` PKCE code challenge, for public clients that exchange the authorization
+    /// code with a [`CodeVerifier`](crate::sso::CodeVerifier) instead of the API key.
+    pub code_challenge: Option<&'a CodeChallenge>,
 }
 
 /// [WorkOS Docs: Get Authorization URL](https://workos.com/docs/reference/sso/authorize/get)
@@ -53,7 +102,7 @@ pub trait GetAuthorizationUrl {
     /// # Examples
     ///
     /// ```
-    /// # use url::ParseError;
+    /// # use url::{ParseError, Url};
     /// # use workos::sso::*;
     /// use workos::{ApiKey, WorkOs};
     ///
@@ -64,11 +113,15 @@ pub trait GetAuthorizationUrl {
     ///     .sso()
     ///     .get_authorization_url(&GetAuthorizationUrlParams {
     ///         client_id: &ClientId::from("client_123456789"),
-    ///         redirect_uri: "https://your-app.com/callback",
+    ///         redirect_uri: &Url::parse("https://your-app.com/callback")?,
     ///         connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
     ///             "conn_01E4ZCR3C56J083X43JQXF3JK5",
     ///         )),
     ///         state: None,
+    ///         domain_hint: None,
+    ///         login_hint: None,
+    ///         provider_scopes: None,
+    ///         code_challenge: None,
     ///     })?;
     /// # Ok(())
     /// # }
@@ -84,43 +137,60 @@ impl GetAuthorizationUrl for Sso<'_> {
             client_id,
             redirect_uri,
             state,
+            domain_hint,
+            login_hint,
+            provider_scopes,
+            code_challenge,
         } = params;
 
-        let query = {
-            let client_id = client_id.to_string();
+        let mut url = self.workos.base_url().join("/sso/authorize")?;
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("response_type", "code")
+                .append_pair("client_id", &client_id.to_string())
+                .append_pair("redirect_uri", redirect_uri.as_str());
 
-            let connection_selector_param = match connection_selector {
+            match connection_selector {
                 ConnectionSelector::Connection(connection_id) => {
-                    ("connection", connection_id.to_string())
+                    query.append_pair("connection", &connection_id.to_string())
                 }
                 ConnectionSelector::Organization(organization_id) => {
-                    ("organization", organization_id.to_string())
+                    query.append_pair("organization", &organization_id.to_string())
                 }
-                ConnectionSelector::Provider(provider) => (
+                ConnectionSelector::Provider(provider) => query.append_pair(
                     "provider",
                     match provider {
-                        Provider::GoogleOauth => "GoogleOAuth".to_string(),
-                        Provider::MicrosoftOauth => "MicrosoftOAuth".to_string(),
+                        KnownOrUnknown::Known(provider) => provider.as_str(),
+                        KnownOrUnknown::Unknown(provider) => provider,
                     },
                 ),
             };
 
-            let mut query_params: querystring::QueryParams = vec![
-                ("response_type", "code"),
-                ("client_id", &client_id),
-                ("redirect_uri", redirect_uri),
-                (connection_selector_param.0, &connection_selector_param.1),
-            ];
-
             if let Some(state) = state {
-                query_params.push(("state", state));
+                query.append_pair("state", state);
+            }
+
+            if let Some(domain_hint) = domain_hint {
+                query.append_pair("domain_hint", domain_hint);
+            }
+
+            if let Some(login_hint) = login_hint {
+                query.append_pair("login_hint", login_hint);
             }
-            String::from(querystring::stringify(query_params).trim_end_matches('&'))
-        };
 
-        self.workos
-            .base_url()
-            .join(&format!("/sso/authorize?{query}"))
+            for provider_scope in provider_scopes.unwrap_or_default() {
+                query.append_pair("provider_scopes", provider_scope);
+            }
+
+            if let Some(code_challenge) = code_challenge {
+                query
+                    .append_pair("code_challenge", &code_challenge.to_string())
+                    .append_pair("code_challenge_method", "S256");
+            }
+        }
+
+        Ok(url)
     }
 }
 
@@ -137,18 +207,22 @@ mod test {
             .sso()
             .get_authorization_url(&GetAuthorizationUrlParams {
                 client_id: &ClientId::from("client_123456789"),
-                redirect_uri: "https://your-app.com/callback",
+                redirect_uri: &Url::parse("https://your-app.com/callback").unwrap(),
                 connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
                     "conn_1234",
                 )),
                 state: None,
+                domain_hint: None,
+                login_hint: None,
+                provider_scopes: None,
+                code_challenge: None,
             })
             .unwrap();
 
         assert_eq!(
             authorization_url,
             Url::parse(
-                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https://your-app.com/callback&connection=conn_1234"
+                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&connection=conn_1234"
             )
             .unwrap()
         )
@@ -162,18 +236,22 @@ mod test {
         let authorization_url = workos_sso
             .get_authorization_url(&GetAuthorizationUrlParams {
                 client_id: &ClientId::from("client_123456789"),
-                redirect_uri: "https://your-app.com/callback",
+                redirect_uri: &Url::parse("https://your-app.com/callback").unwrap(),
                 connection_selector: ConnectionSelector::Organization(&OrganizationId::from(
                     "org_1234",
                 )),
                 state: None,
+                domain_hint: None,
+                login_hint: None,
+                provider_scopes: None,
+                code_challenge: None,
             })
             .unwrap();
 
         assert_eq!(
             authorization_url,
             Url::parse(
-                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https://your-app.com/callback&organization=org_1234"
+                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&organization=org_1234"
             )
             .unwrap()
         )
@@ -187,18 +265,153 @@ mod test {
         let authorization_url = workos_sso
             .get_authorization_url(&GetAuthorizationUrlParams {
                 client_id: &ClientId::from("client_123456789"),
-                redirect_uri: "https://your-app.com/callback",
-                connection_selector: ConnectionSelector::Provider(&Provider::GoogleOauth),
+                redirect_uri: &Url::parse("https://your-app.com/callback").unwrap(),
+                connection_selector: ConnectionSelector::Provider(KnownOrUnknown::Known(
+                    &Provider::GoogleOauth,
+                )),
+                state: None,
+                domain_hint: None,
+                login_hint: None,
+                provider_scopes: None,
+                code_challenge: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            authorization_url,
+            Url::parse(
+                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&provider=GoogleOAuth"
+            )
+            .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_builds_an_authorization_url_with_a_code_challenge() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let authorization_url = workos
+            .sso()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: &ClientId::from("client_123456789"),
+                redirect_uri: &Url::parse("https://your-app.com/callback").unwrap(),
+                connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
+                    "conn_1234",
+                )),
                 state: None,
+                domain_hint: None,
+                login_hint: None,
+                provider_scopes: None,
+                code_challenge: Some(&CodeChallenge::from(
+                    "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM",
+                )),
             })
             .unwrap();
 
         assert_eq!(
             authorization_url,
             Url::parse(
-                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https://your-app.com/callback&provider=GoogleOAuth"
+                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&connection=conn_1234&code_challenge=E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM&code_challenge_method=S256"
             )
             .unwrap()
         )
     }
+
+    #[test]
+    fn it_builds_an_authorization_url_with_hints_and_provider_scopes() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let authorization_url = workos
+            .sso()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: &ClientId::from("client_123456789"),
+                redirect_uri: &Url::parse("https://your-app.com/callback").unwrap(),
+                connection_selector: ConnectionSelector::Provider(KnownOrUnknown::Known(
+                    &Provider::GitHubOauth,
+                )),
+                state: None,
+                domain_hint: Some("foo-corp.com"),
+                login_hint: Some("todd@foo-corp.com"),
+                provider_scopes: Some(&["repo", "read:org"]),
+                code_challenge: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            authorization_url,
+            Url::parse(
+                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&provider=GitHubOAuth&domain_hint=foo-corp.com&login_hint=todd%40foo-corp.com&provider_scopes=repo&provider_scopes=read%3Aorg"
+            )
+            .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_builds_an_authorization_url_when_given_an_unknown_provider() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let authorization_url = workos
+            .sso()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: &ClientId::from("client_123456789"),
+                redirect_uri: &Url::parse("https://your-app.com/callback").unwrap(),
+                connection_selector: ConnectionSelector::Provider(KnownOrUnknown::Unknown(
+                    "LinkedInOAuth",
+                )),
+                state: None,
+                domain_hint: None,
+                login_hint: None,
+                provider_scopes: None,
+                code_challenge: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            authorization_url,
+            Url::parse(
+                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&provider=LinkedInOAuth"
+            )
+            .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_percent_encodes_hints_and_provider_scopes() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let authorization_url = workos
+            .sso()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: &ClientId::from("client_123456789"),
+                redirect_uri: &Url::parse("https://your-app.com/callback?next=/home").unwrap(),
+                connection_selector: ConnectionSelector::Provider(KnownOrUnknown::Known(
+                    &Provider::GoogleOauth,
+                )),
+                state: None,
+                domain_hint: None,
+                login_hint: Some("todd+sso&admin=1@foo-corp.com"),
+                provider_scopes: Some(&["https://www.googleapis.com/auth/calendar#read"]),
+                code_challenge: None,
+            })
+            .unwrap();
+
+        let query = authorization_url
+            .query_pairs()
+            .into_owned()
+            .collect::<Vec<_>>();
+
+        assert!(query.contains(&(
+            "redirect_uri".to_string(),
+            "https://your-app.com/callback?next=/home".to_string()
+        )));
+        assert!(query.contains(&(
+            "login_hint".to_string(),
+            "todd+sso&admin=1@foo-corp.com".to_string()
+        )));
+        assert!(query.contains(&(
+            "provider_scopes".to_string(),
+            "https://www.googleapis.com/auth/calendar#read".to_string()
+        )));
+        assert!(!query.iter().any(|(key, _)| key == "admin"));
+    }
 }
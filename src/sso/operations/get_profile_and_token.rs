@@ -1,9 +1,15 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use reqwest::{Response, StatusCode};
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use serde_json::Value;
 use thiserror::Error;
 
-use crate::sso::{AccessToken, AuthorizationCode, ClientId, Profile, Sso};
+use crate::sso::{
+    AccessToken, AuthorizationCode, ClientId, CodeVerifier, OauthTokens, Profile, Sso,
+};
 use crate::{WorkOsError, WorkOsResult};
 
 /// The parameters for [`GetProfileAndToken`].
@@ -15,16 +21,26 @@ pub struct GetProfileAndTokenParams<'a> {
 
     /// The authorization code to exchange for the profile and token.
     pub code: &'a AuthorizationCode,
+
+    /// The PKCE code verifier for the code challenge that was used to initiate SSO.
+    ///
+    /// When provided, the code verifier is sent instead of the API key as the client
+    /// secret.
+    pub code_verifier: Option<&'a CodeVerifier>,
 }
 
 /// The response for [`GetProfileAndToken`].
 #[derive(Debug, Deserialize)]
-pub struct GetProfileAndTokenResponse {
+pub struct GetProfileAndTokenResponse<TCustomAttributes = HashMap<String, Value>> {
     /// An access token that can be exchanged for the user profile.
     pub access_token: AccessToken,
 
     /// The user profile.
-    pub profile: Profile,
+    #[serde(bound(deserialize = "TCustomAttributes: DeserializeOwned + Default"))]
+    pub profile: Profile<TCustomAttributes>,
+
+    /// The tokens issued by the OAuth provider, when the connection is an OAuth connection.
+    pub oauth_tokens: Option<OauthTokens>,
 }
 
 /// An error returned from [`GetProfileAndToken`].
@@ -73,6 +89,8 @@ impl HandleGetProfileAndTokenError for Response {
 /// [WorkOS Docs: Get a Profile and Token](https://workos.com/docs/reference/sso/profile/token)
 #[async_trait]
 pub trait GetProfileAndToken {
+    /// Exchanges an authorization code for an access token and the user's [`Profile`].
+    ///
     /// [WorkOS Docs: Get a Profile and Token](https://workos.com/docs/reference/sso/profile/token)
     ///
     /// # Examples
@@ -90,6 +108,7 @@ pub trait GetProfileAndToken {
     ///     .get_profile_and_token(&GetProfileAndTokenParams {
     ///         client_id: &ClientId::from("client_123456789"),
     ///         code: &AuthorizationCode::from("01G6RSWVD06ZQ6JB4YS5W521S3"),
+    ///         code_verifier: None,
     ///     })
     ///     .await?;
     /// # Ok(())
@@ -99,6 +118,43 @@ pub trait GetProfileAndToken {
         &self,
         params: &GetProfileAndTokenParams<'_>,
     ) -> WorkOsResult<GetProfileAndTokenResponse, GetProfileAndTokenError>;
+
+    /// Exchanges an authorization code for an access token and the user's [`Profile`],
+    /// decoding its custom attributes into `TCustomAttributes`.
+    ///
+    /// [WorkOS Docs: Get a Profile and Token](https://workos.com/docs/reference/sso/profile/token)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde::Deserialize;
+    /// # use workos::WorkOsResult;
+    /// # use workos::sso::*;
+    /// use workos::{ApiKey, WorkOs};
+    ///
+    /// #[derive(Default, Deserialize)]
+    /// struct CustomAttributes {
+    ///     department: Option<String>,
+    /// }
+    ///
+    /// # async fn run() -> WorkOsResult<(), GetProfileAndTokenError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let GetProfileAndTokenResponse { profile, .. } = workos
+    ///     .sso()
+    ///     .get_profile_and_token_as::<CustomAttributes>(&GetProfileAndTokenParams {
+    ///         client_id: &ClientId::from("client_123456789"),
+    ///         code: &AuthorizationCode::from("01G6RSWVD06ZQ6JB4YS5W521S3"),
+    ///         code_verifier: None,
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_profile_and_token_as<TCustomAttributes: DeserializeOwned + Default>(
+        &self,
+        params: &GetProfileAndTokenParams<'_>,
+    ) -> WorkOsResult<GetProfileAndTokenResponse<TCustomAttributes>, GetProfileAndTokenError>;
 }
 
 #[async_trait]
@@ -107,15 +163,29 @@ impl GetProfileAndToken for Sso<'_> {
         &self,
         params: &GetProfileAndTokenParams<'_>,
     ) -> WorkOsResult<GetProfileAndTokenResponse, GetProfileAndTokenError> {
-        let &GetProfileAndTokenParams { client_id, code } = params;
+        self.get_profile_and_token_as(params).await
+    }
+
+    async fn get_profile_and_token_as<TCustomAttributes: DeserializeOwned + Default>(
+        &self,
+        params: &GetProfileAndTokenParams<'_>,
+    ) -> WorkOsResult<GetProfileAndTokenResponse<TCustomAttributes>, GetProfileAndTokenError> {
+        let &GetProfileAndTokenParams {
+            client_id,
+            code,
+            code_verifier,
+        } = params;
 
         let url = self.workos.base_url().join("/sso/token")?;
-        let params = [
-            ("client_id", &client_id.to_string()),
-            ("client_secret", &self.workos.key().to_string()),
-            ("grant_type", &"authorization_code".to_string()),
-            ("code", &code.to_string()),
+        let mut params = vec![
+            ("client_id", client_id.to_string()),
+            ("grant_type", "authorization_code".to_string()),
+            ("code", code.to_string()),
         ];
+        match code_verifier {
+            Some(code_verifier) => params.push(("code_verifier", code_verifier.to_string())),
+            None => params.push(("client_secret", self.workos.key().to_string())),
+        }
         let get_profile_and_token_response = self
             .workos
             .client()
@@ -125,7 +195,7 @@ impl GetProfileAndToken for Sso<'_> {
             .await?
             .handle_get_profile_and_token_error()
             .await?
-            .json::<GetProfileAndTokenResponse>()
+            .json::<GetProfileAndTokenResponse<TCustomAttributes>>()
             .await?;
 
         Ok(get_profile_and_token_response)
@@ -188,6 +258,7 @@ mod test {
             .get_profile_and_token(&GetProfileAndTokenParams {
                 client_id: &ClientId::from("client_1234"),
                 code: &AuthorizationCode::from("abc123"),
+                code_verifier: None,
             })
             .await
             .unwrap();
@@ -202,6 +273,111 @@ mod test {
         )
     }
 
+    #[tokio::test]
+    async fn it_sends_the_code_verifier_instead_of_the_client_secret() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock("POST", "/sso/token")
+            .match_body(
+                "client_id=client_1234&grant_type=authorization_code&code=abc123&code_verifier=dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk",
+            )
+            .with_status(200)
+            .with_body(
+                json!({
+                  "access_token": "01DMEK0J53CVMC32CK5SE0KZ8Q",
+                  "profile": {
+                    "id": "prof_01DMC79VCBZ0NY2099737PSVF1",
+                    "connection_id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                    "connection_type": "okta",
+                    "email": "todd@foo-corp.com",
+                    "first_name": "Todd",
+                    "idp_id": "00u1a0ufowBJlzPlk357",
+                    "last_name": "Rundgren",
+                    "object": "profile",
+                    "raw_attributes": {}
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = workos
+            .sso()
+            .get_profile_and_token(&GetProfileAndTokenParams {
+                client_id: &ClientId::from("client_1234"),
+                code: &AuthorizationCode::from("abc123"),
+                code_verifier: Some(&CodeVerifier::from(
+                    "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk",
+                )),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.access_token,
+            AccessToken::from("01DMEK0J53CVMC32CK5SE0KZ8Q")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_oauth_tokens_for_an_oauth_connection() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock("POST", "/sso/token")
+            .with_status(200)
+            .with_body(
+                json!({
+                  "access_token": "01DMEK0J53CVMC32CK5SE0KZ8Q",
+                  "profile": {
+                    "id": "prof_01DMC79VCBZ0NY2099737PSVF1",
+                    "connection_id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                    "connection_type": "GoogleOAuth",
+                    "email": "todd@foo-corp.com",
+                    "first_name": "Todd",
+                    "idp_id": "00u1a0ufowBJlzPlk357",
+                    "last_name": "Rundgren",
+                    "object": "profile",
+                    "raw_attributes": {}
+                  },
+                  "oauth_tokens": {
+                    "access_token": "ya29.a0AfB_byC",
+                    "refresh_token": "1//04dJ6KqZ",
+                    "expires_at": 1735141800,
+                    "scopes": ["openid", "email"]
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = workos
+            .sso()
+            .get_profile_and_token(&GetProfileAndTokenParams {
+                client_id: &ClientId::from("client_1234"),
+                code: &AuthorizationCode::from("abc123"),
+                code_verifier: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.oauth_tokens.map(|tokens| tokens.scopes),
+            Some(vec!["openid".to_string(), "email".to_string()])
+        )
+    }
+
     #[tokio::test]
     async fn it_returns_an_unauthorized_error_with_an_invalid_client() {
         let mut server = mockito::Server::new_async().await;
@@ -228,6 +404,7 @@ mod test {
             .get_profile_and_token(&GetProfileAndTokenParams {
                 client_id: &ClientId::from("client_1234"),
                 code: &AuthorizationCode::from("abc123"),
+                code_verifier: None,
             })
             .await;
 
@@ -260,6 +437,7 @@ mod test {
             .get_profile_and_token(&GetProfileAndTokenParams {
                 client_id: &ClientId::from("client_1234"),
                 code: &AuthorizationCode::from("abc123"),
+                code_verifier: None,
             })
             .await;
 
@@ -292,6 +470,7 @@ mod test {
             .get_profile_and_token(&GetProfileAndTokenParams {
                 client_id: &ClientId::from("client_1234"),
                 code: &AuthorizationCode::from("abc123"),
+                code_verifier: None,
             })
             .await;
 
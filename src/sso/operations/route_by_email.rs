@@ -0,0 +1,580 @@
+use std::collections::HashMap;
+use std::pin::pin;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use futures_util::stream::TryStreamExt;
+use thiserror::Error;
+use url::Url;
+
+use crate::organizations::{ListOrganizations, ListOrganizationsParams, OrganizationId};
+use crate::sso::{
+    ClientId, ConnectionId, ConnectionSelector, ConnectionState, GetAuthorizationUrl,
+    GetAuthorizationUrlParams, ListConnections, ListConnectionsParams, Sso,
+};
+use crate::{paginate, KnownOrUnknown, PaginationParams, WorkOsError, WorkOsResult};
+
+/// A cache of the SSO connections resolved by [`RouteByEmail`], keyed by email domain.
+///
+/// Both domains with and without an active connection are cached.
+#[derive(Debug)]
+pub struct SsoRouteCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, Option<SsoTarget>)>>,
+}
+
+impl SsoRouteCache {
+    /// Returns a new [`SsoRouteCache`] whose entries expire after `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, domain: &str) -> Option<Option<SsoTarget>> {
+        let entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+
+        entries
+            .get(domain)
+            .filter(|(cached_at, _)| cached_at.elapsed() < self.ttl)
+            .map(|(_, target)| target.clone())
+    }
+
+    fn insert(&self, domain: String, target: Option<SsoTarget>) {
+        let mut entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+
+        entries.retain(|_, (cached_at, _)| cached_at.elapsed() < self.ttl);
+        entries.insert(domain, (Instant::now(), target));
+    }
+}
+
+impl Default for SsoRouteCache {
+    /// Returns a new [`SsoRouteCache`] whose entries expire after 5 minutes.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5 * 60))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SsoTarget {
+    organization_id: OrganizationId,
+    connection_id: ConnectionId,
+}
+
+/// The parameters for [`RouteByEmail`].
+#[derive(Debug)]
+pub struct RouteByEmailParams<'a> {
+    /// The email address entered by the user.
+    pub email: &'a str,
+
+    /// The client ID for the environment in which SSO is being initiated.
+    pub client_id: &'a ClientId,
+
+    /// The redirect URI the user will be redirected to after successfully signing in.
+    pub redirect_uri: &'a Url,
+
+    /// The state parameter that will be passed back to the redirect URI.
+    pub state: Option<&'a str>,
+
+    /// The cache to use for lookups, if any.
+    pub cache: Option<&'a SsoRouteCache>,
+}
+
+/// The route returned from [`RouteByEmail`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SsoRoute {
+    /// The user should sign in with SSO.
+    Sso {
+        /// The ID of the organization that owns the email domain.
+        organization_id: OrganizationId,
+
+        /// The ID of the active connection of the organization.
+        connection_id: ConnectionId,
+
+        /// The authorization URL to redirect the user to.
+        authorization_url: Url,
+    },
+
+    /// The email domain does not belong to an organization with an active connection.
+    NoSso,
+}
+
+/// An error returned from [`RouteByEmail`].
+#[derive(Debug, Error)]
+pub enum RouteByEmailError {
+    /// The email address has no domain.
+    #[error("invalid email address")]
+    InvalidEmail,
+
+    /// The organizations or connections could not be listed.
+    #[error("failed to list the organizations or connections")]
+    List,
+}
+
+impl From<RouteByEmailError> for WorkOsError<RouteByEmailError> {
+    fn from(err: RouteByEmailError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+/// Routes users to SSO based on the domain of their email address.
+#[async_trait]
+pub trait RouteByEmail {
+    /// Determines whether a user should sign in with SSO, based on the domain of their
+    /// email address.
+    ///
+    /// The organization that owns the domain is looked up with
+    /// [`ListOrganizations`], and the first of its active connections with
+    /// [`ListConnections`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use url::Url;
+    /// # use workos::WorkOsResult;
+    /// # use workos::sso::*;
+    /// use workos::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), RouteByEmailError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    /// let cache = SsoRouteCache::default();
+    ///
+    /// let route = workos
+    ///     .sso()
+    ///     .route_by_email(&RouteByEmailParams {
+    ///         email: "todd@foo-corp.com",
+    ///         client_id: &ClientId::from("client_123456789"),
+    ///         redirect_uri: &Url::parse("https://your-app.com/callback")?,
+    ///         state: None,
+    ///         cache: Some(&cache),
+    ///     })
+    ///     .await?;
+    ///
+    /// match route {
+    ///     SsoRoute::Sso {
+    ///         authorization_url, ..
+    ///     } => println!("Redirect to {authorization_url}"),
+    ///     SsoRoute::NoSso => println!("Ask for a password"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn route_by_email(
+        &self,
+        params: &RouteByEmailParams<'_>,
+    ) -> WorkOsResult<SsoRoute, RouteByEmailError>;
+}
+
+#[async_trait]
+impl RouteByEmail for Sso<'_> {
+    async fn route_by_email(
+        &self,
+        params: &RouteByEmailParams<'_>,
+    ) -> WorkOsResult<SsoRoute, RouteByEmailError> {
+        let &RouteByEmailParams {
+            email,
+            client_id,
+            redirect_uri,
+            state,
+            cache,
+        } = params;
+
+        let domain = match email.rsplit_once('@') {
+            Some((_, domain)) if !domain.is_empty() => domain.to_lowercase(),
+            _ => return Err(RouteByEmailError::InvalidEmail.into()),
+        };
+
+        let target = match cache.and_then(|cache| cache.get(&domain)) {
+            Some(target) => target,
+            None => {
+                let target = find_sso_target(self, &domain).await?;
+                if let Some(cache) = cache {
+                    cache.insert(domain, target.clone());
+                }
+                target
+            }
+        };
+
+        let Some(SsoTarget {
+            organization_id,
+            connection_id,
+        }) = target
+        else {
+            return Ok(SsoRoute::NoSso);
+        };
+
+        let authorization_url = self.get_authorization_url(&GetAuthorizationUrlParams {
+            client_id,
+            redirect_uri,
+            connection_selector: ConnectionSelector::Connection(&connection_id),
+            state,
+            domain_hint: None,
+            login_hint: Some(email),
+            provider_scopes: None,
+            code_challenge: None,
+        })?;
+
+        Ok(SsoRoute::Sso {
+            organization_id,
+            connection_id,
+            authorization_url,
+        })
+    }
+}
+
+async fn find_sso_target(
+    sso: &Sso<'_>,
+    domain: &str,
+) -> WorkOsResult<Option<SsoTarget>, RouteByEmailError> {
+    let organizations = sso
+        .workos
+        .organizations()
+        .list_organizations(&ListOrganizationsParams {
+            domains: Some(vec![domain].into()),
+            ..Default::default()
+        })
+        .await
+        .map_err(|err| err.map_operation(|()| RouteByEmailError::List))?;
+
+    let Some(organization) = organizations.data.into_iter().find(|organization| {
+        organization
+            .domains
+            .iter()
+            .any(|organization_domain| organization_domain.domain.eq_ignore_ascii_case(domain))
+    }) else {
+        return Ok(None);
+    };
+
+    let organization_id = &organization.id;
+    let mut connections = pin!(paginate(|after| async move {
+        sso.list_connections(&ListConnectionsParams {
+            pagination: PaginationParams {
+                after: after.as_deref(),
+                ..Default::default()
+            },
+            organization_id: Some(organization_id),
+            ..Default::default()
+        })
+        .await
+    }));
+    while let Some(page) = connections
+        .try_next()
+        .await
+        .map_err(|err| err.map_operation(|()| RouteByEmailError::List))?
+    {
+        if let Some(connection) = page
+            .into_iter()
+            .find(|connection| connection.state == KnownOrUnknown::Known(ConnectionState::Active))
+        {
+            return Ok(Some(SsoTarget {
+                organization_id: organization_id.clone(),
+                connection_id: connection.id,
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::{ApiKey, WorkOs};
+
+    fn mock_organizations(server: &mut mockito::Server) -> mockito::Mock {
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::UrlEncoded(
+                "domains[]".to_string(),
+                "foo-corp.com".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                      "object": "organization",
+                      "name": "Foo Corp",
+                      "allow_profiles_outside_organization": false,
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "domains": [
+                        {
+                          "domain": "foo-corp.com",
+                          "id": "org_domain_01EHZNVPK2QXHMVWCEDQEKY69A",
+                          "object": "organization_domain"
+                        }
+                      ]
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+    }
+
+    #[tokio::test]
+    async fn it_routes_an_email_to_the_active_connection_of_its_organization() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let organizations_mock = mock_organizations(&mut server)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let connections_mock = server
+            .mock("GET", "/connections")
+            .match_query(Matcher::UrlEncoded(
+                "organization_id".to_string(),
+                "org_01EHZNVPK3SFK441A1RGBFSHRT".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "connection",
+                      "id": "conn_01E2NPPCT7XQ2MVVYDHWGK1WN4",
+                      "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                      "connection_type": "OktaSAML",
+                      "name": "Foo Corp (old)",
+                      "state": "inactive",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    },
+                    {
+                      "object": "connection",
+                      "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                      "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                      "connection_type": "OktaSAML",
+                      "name": "Foo Corp",
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cache = SsoRouteCache::default();
+        let redirect_uri = Url::parse("https://your-app.com/callback").unwrap();
+        let params = RouteByEmailParams {
+            email: "Todd@Foo-Corp.com",
+            client_id: &ClientId::from("client_123456789"),
+            redirect_uri: &redirect_uri,
+            state: None,
+            cache: Some(&cache),
+        };
+
+        let route = workos.sso().route_by_email(&params).await.unwrap();
+        let cached_route = workos.sso().route_by_email(&params).await.unwrap();
+
+        organizations_mock.assert_async().await;
+        connections_mock.assert_async().await;
+        assert_eq!(route, cached_route);
+        assert_matches!(
+            route,
+            SsoRoute::Sso {
+                connection_id,
+                authorization_url,
+                ..
+            } if connection_id == ConnectionId::from("conn_01E4ZCR3C56J083X43JQXF3JK5")
+                && authorization_url.as_str().contains("connection=conn_01E4ZCR3C56J083X43JQXF3JK5")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_finds_an_active_connection_on_a_later_page() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _organizations_mock = mock_organizations(&mut server).create_async().await;
+
+        let _first_page_mock = server
+            .mock("GET", "/connections")
+            .match_query(Matcher::UrlEncoded(
+                "organization_id".to_string(),
+                "org_01EHZNVPK3SFK441A1RGBFSHRT".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "connection",
+                      "id": "conn_01E2NPPCT7XQ2MVVYDHWGK1WN4",
+                      "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                      "connection_type": "OktaSAML",
+                      "name": "Foo Corp (old)",
+                      "state": "inactive",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": "conn_01E2NPPCT7XQ2MVVYDHWGK1WN4"
+                  }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let _second_page_mock = server
+            .mock("GET", "/connections")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(
+                    "organization_id".to_string(),
+                    "org_01EHZNVPK3SFK441A1RGBFSHRT".to_string(),
+                ),
+                Matcher::UrlEncoded(
+                    "after".to_string(),
+                    "conn_01E2NPPCT7XQ2MVVYDHWGK1WN4".to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "connection",
+                      "id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                      "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                      "connection_type": "OktaSAML",
+                      "name": "Foo Corp",
+                      "state": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let route = workos
+            .sso()
+            .route_by_email(&RouteByEmailParams {
+                email: "todd+sso@foo-corp.com",
+                client_id: &ClientId::from("client_123456789"),
+                redirect_uri: &Url::parse("https://your-app.com/callback").unwrap(),
+                state: None,
+                cache: None,
+            })
+            .await
+            .unwrap();
+
+        let SsoRoute::Sso {
+            connection_id,
+            authorization_url,
+            ..
+        } = route
+        else {
+            panic!("expected an SSO route, got {route:?}");
+        };
+
+        assert_eq!(
+            connection_id,
+            ConnectionId::from("conn_01E4ZCR3C56J083X43JQXF3JK5")
+        );
+        assert!(authorization_url
+            .query_pairs()
+            .any(|(key, value)| key == "login_hint" && value == "todd+sso@foo-corp.com"));
+    }
+
+    #[tokio::test]
+    async fn it_returns_no_sso_when_the_organization_has_no_active_connection() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _organizations_mock = mock_organizations(&mut server).create_async().await;
+
+        let _connections_mock = server
+            .mock("GET", "/connections")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let route = workos
+            .sso()
+            .route_by_email(&RouteByEmailParams {
+                email: "todd@foo-corp.com",
+                client_id: &ClientId::from("client_123456789"),
+                redirect_uri: &Url::parse("https://your-app.com/callback").unwrap(),
+                state: None,
+                cache: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(route, SsoRoute::NoSso)
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_for_an_email_without_a_domain() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let result = workos
+            .sso()
+            .route_by_email(&RouteByEmailParams {
+                email: "todd",
+                client_id: &ClientId::from("client_123456789"),
+                redirect_uri: &Url::parse("https://your-app.com/callback").unwrap(),
+                state: None,
+                cache: None,
+            })
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(RouteByEmailError::InvalidEmail))
+        )
+    }
+}
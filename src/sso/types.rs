@@ -1,13 +1,23 @@
 mod access_token;
 mod authorization_code;
+mod callback_params;
 mod client_id;
 mod connection;
 mod connection_type;
+mod oauth_tokens;
+mod pkce;
 mod profile;
+mod provider_attributes;
+mod state_codec;
 
 pub use access_token::*;
 pub use authorization_code::*;
+pub use callback_params::*;
 pub use client_id::*;
 pub use connection::*;
 pub use connection_type::*;
+pub use oauth_tokens::*;
+pub use pkce::*;
 pub use profile::*;
+pub use provider_attributes::*;
+pub use state_codec::*;
@@ -0,0 +1,205 @@
+use serde::de::value::{self, StrDeserializer};
+use serde::de::IntoDeserializer;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use url::{form_urlencoded, Url};
+
+use crate::sso::AuthorizationCode;
+use crate::KnownOrUnknown;
+
+/// The error code of an [`SsoCallbackError`].
+///
+/// [WorkOS Docs: SSO Redirect Errors](https://workos.com/docs/reference/sso/get-authorization-url/error-codes)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SsoCallbackErrorCode {
+    /// The user denied the authorization request, or the IdP rejected it.
+    AccessDenied,
+
+    /// More than one connection matched the connection selector.
+    AmbiguousConnectionSelector,
+
+    /// The domain of the user does not match a domain of the connection.
+    ConnectionDomainInvalid,
+
+    /// The connection does not exist or is not active.
+    ConnectionInvalid,
+
+    /// The connection type is not supported for the requested flow.
+    ConnectionStrategyInvalid,
+
+    /// The connection is not linked to an organization.
+    ConnectionUnlinked,
+
+    /// The connection selector is invalid.
+    InvalidConnectionSelector,
+
+    /// The organization does not exist or has no active connection.
+    OrganizationInvalid,
+
+    /// The OAuth provider returned an error.
+    OauthFailed,
+
+    /// The user's email domain is not a domain of the organization.
+    ProfileNotAllowedOutsideOrganization,
+
+    /// WorkOS encountered an unexpected error.
+    ServerError,
+}
+
+/// An error returned to the redirect URI after a failed SSO attempt.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SsoCallbackError {
+    /// WorkOS redirected with an `error` instead of a `code`.
+    #[error("SSO callback returned an error")]
+    Redirect {
+        /// The error code.
+        error: KnownOrUnknown<SsoCallbackErrorCode, String>,
+
+        /// The description of the error.
+        error_description: Option<String>,
+
+        /// The state parameter that was passed to the authorization URL.
+        state: Option<String>,
+    },
+
+    /// The redirect had neither a `code` nor an `error`.
+    #[error("missing authorization code")]
+    MissingCode,
+}
+
+/// The query parameters WorkOS passes to the redirect URI after a successful SSO attempt.
+///
+/// # Examples
+///
+/// ```
+/// # use url::Url;
+/// # use workos::sso::*;
+/// # fn run() -> Result<(), SsoCallbackError> {
+/// let url = Url::parse("https://your-app.com/callback?code=01E2RJ4C05B52KKZ8FSRDAP23J&state=abc").unwrap();
+///
+/// let CallbackParams { code, state } = CallbackParams::from_url(&url)?;
+/// # assert_eq!(code, AuthorizationCode::from("01E2RJ4C05B52KKZ8FSRDAP23J"));
+/// # assert_eq!(state.as_deref(), Some("abc"));
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallbackParams {
+    /// The authorization code to pass to
+    /// [`GetProfileAndToken`](crate::sso::GetProfileAndToken).
+    pub code: AuthorizationCode,
+
+    /// The state parameter that was passed to the authorization URL.
+    pub state: Option<String>,
+}
+
+impl CallbackParams {
+    /// Parses the callback parameters from the URL WorkOS redirected to.
+    pub fn from_url(url: &Url) -> Result<Self, SsoCallbackError> {
+        Self::from_query(url.query().unwrap_or_default())
+    }
+
+    /// Parses the callback parameters from the query string of the URL WorkOS redirected to.
+    ///
+    /// A leading `?` is ignored.
+    pub fn from_query(query: &str) -> Result<Self, SsoCallbackError> {
+        let query = query.strip_prefix('?').unwrap_or(query);
+
+        let mut code = None;
+        let mut state = None;
+        let mut error = None;
+        let mut error_description = None;
+
+        for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "code" => code = Some(value.into_owned()),
+                "state" => state = Some(value.into_owned()),
+                "error" => error = Some(value.into_owned()),
+                "error_description" => error_description = Some(value.into_owned()),
+                _ => {}
+            }
+        }
+
+        if let Some(error) = error {
+            let deserializer: StrDeserializer<'_, value::Error> =
+                error.as_str().into_deserializer();
+            let error = SsoCallbackErrorCode::deserialize(deserializer)
+                .map(KnownOrUnknown::Known)
+                .unwrap_or(KnownOrUnknown::Unknown(error));
+
+            return Err(SsoCallbackError::Redirect {
+                error,
+                error_description,
+                state,
+            });
+        }
+
+        match code {
+            Some(code) => Ok(Self {
+                code: AuthorizationCode::from(code),
+                state,
+            }),
+            None => Err(SsoCallbackError::MissingCode),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_the_code_and_state_from_a_url() {
+        let url =
+            Url::parse("https://your-app.com/callback?code=01E2RJ4C05B52KKZ8FSRDAP23J&state=a%2Fb")
+                .unwrap();
+
+        assert_eq!(
+            CallbackParams::from_url(&url),
+            Ok(CallbackParams {
+                code: AuthorizationCode::from("01E2RJ4C05B52KKZ8FSRDAP23J"),
+                state: Some("a/b".to_string()),
+            })
+        )
+    }
+
+    #[test]
+    fn it_parses_a_known_error_from_a_query() {
+        assert_eq!(
+            CallbackParams::from_query(
+                "?error=profile_not_allowed_outside_organization&error_description=Not+allowed&state=abc"
+            ),
+            Err(SsoCallbackError::Redirect {
+                error: KnownOrUnknown::Known(
+                    SsoCallbackErrorCode::ProfileNotAllowedOutsideOrganization
+                ),
+                error_description: Some("Not allowed".to_string()),
+                state: Some("abc".to_string()),
+            })
+        )
+    }
+
+    #[test]
+    fn it_parses_an_unknown_error_from_a_query() {
+        assert_matches!(
+            CallbackParams::from_query("error=something_new"),
+            Err(SsoCallbackError::Redirect {
+                error: KnownOrUnknown::Unknown(error),
+                error_description: None,
+                state: None,
+            }) if error == "something_new"
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_code_is_missing() {
+        assert_eq!(
+            CallbackParams::from_query("state=abc"),
+            Err(SsoCallbackError::MissingCode)
+        )
+    }
+}
@@ -7,7 +7,7 @@ use crate::sso::ConnectionType;
 use crate::{KnownOrUnknown, Timestamps};
 
 /// The ID of a [`Connection`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct ConnectionId(String);
 
 impl Display for ConnectionId {
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{KnownOrUnknown, ProviderProtocol};
+
 /// The type of a [`Connection`](crate::sso::Connection).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConnectionType {
@@ -166,11 +168,155 @@ pub enum ConnectionType {
     VmwareSaml,
 }
 
+impl ConnectionType {
+    /// Returns the human-readable name of the connection type.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::AdFsSaml => "AD FS SAML",
+            Self::AdpOidc => "ADP OpenID Connect (OIDC)",
+            Self::Auth0Saml => "Auth0 SAML",
+            Self::AzureSaml => "Azure Active Directory (AD) SAML",
+            Self::CasSaml => "CAS SAML",
+            Self::ClassLinkSaml => "ClassLink SAML",
+            Self::CloudflareSaml => "Cloudflare SAML",
+            Self::CyberArkSaml => "CyberArk SAML",
+            Self::DuoSaml => "Duo SAML",
+            Self::GenericOidc => "Generic OpenID Connect (OIDC)",
+            Self::GenericSaml => "Generic SAML",
+            Self::GoogleOauth => "Google OAuth",
+            Self::GoogleSaml => "Google SAML",
+            Self::JumpCloudSaml => "JumpCloud SAML",
+            Self::KeycloakSaml => "Keycloak SAML",
+            Self::MicrosoftOauth => "Microsoft OAuth",
+            Self::MiniOrangeSaml => "miniOrange SAML",
+            Self::NetIqSaml => "NetIQ SAML",
+            Self::OktaSaml => "Okta SAML",
+            Self::OneLoginSaml => "OneLogin SAML",
+            Self::OracleSaml => "Oracle SAML",
+            Self::PingFederateSaml => "PingFederate SAML",
+            Self::PingOneSaml => "PingOne SAML",
+            Self::SalesforceSaml => "Salesforce SAML",
+            Self::ShibbolethSaml => "Shibboleth SAML",
+            Self::SimpleSamlPhpSaml => "SimpleSAMLphp SAML",
+            Self::VmwareSaml => "VMware SAML",
+        }
+    }
+
+    /// Returns the protocol family of the connection type.
+    pub fn protocol(&self) -> ProviderProtocol {
+        match self {
+            Self::AdFsSaml
+            | Self::Auth0Saml
+            | Self::AzureSaml
+            | Self::CasSaml
+            | Self::ClassLinkSaml
+            | Self::CloudflareSaml
+            | Self::CyberArkSaml
+            | Self::DuoSaml
+            | Self::GenericSaml
+            | Self::GoogleSaml
+            | Self::JumpCloudSaml
+            | Self::KeycloakSaml
+            | Self::MiniOrangeSaml
+            | Self::NetIqSaml
+            | Self::OktaSaml
+            | Self::OneLoginSaml
+            | Self::OracleSaml
+            | Self::PingFederateSaml
+            | Self::PingOneSaml
+            | Self::SalesforceSaml
+            | Self::ShibbolethSaml
+            | Self::SimpleSamlPhpSaml
+            | Self::VmwareSaml => ProviderProtocol::Saml,
+            Self::AdpOidc | Self::GenericOidc => ProviderProtocol::Oidc,
+            Self::GoogleOauth | Self::MicrosoftOauth => ProviderProtocol::Oauth,
+        }
+    }
+
+    /// Returns the URL of the WorkOS integration guide for the connection type.
+    pub fn docs_url(&self) -> &'static str {
+        match self {
+            Self::AdFsSaml => "https://workos.com/docs/integrations/adfs-saml",
+            Self::AdpOidc => "https://workos.com/docs/integrations/adp-oidc",
+            Self::Auth0Saml => "https://workos.com/docs/integrations/auth0-saml",
+            Self::AzureSaml => "https://workos.com/docs/integrations/azure-ad-saml",
+            Self::CasSaml => "https://workos.com/docs/integrations/cas-saml",
+            Self::ClassLinkSaml => "https://workos.com/docs/integrations/classlink-saml",
+            Self::CloudflareSaml => "https://workos.com/docs/integrations/cloudflare-saml",
+            Self::CyberArkSaml => "https://workos.com/docs/integrations/cyberark-saml",
+            Self::DuoSaml => "https://workos.com/docs/integrations/duo-saml",
+            Self::GenericOidc => "https://workos.com/docs/integrations/oidc",
+            Self::GenericSaml => "https://workos.com/docs/integrations/generic-saml",
+            Self::GoogleOauth => "https://workos.com/docs/integrations/g-suite-oauth",
+            Self::GoogleSaml => "https://workos.com/docs/integrations/google-saml",
+            Self::JumpCloudSaml => "https://workos.com/docs/integrations/jumpcloud-saml",
+            Self::KeycloakSaml => "https://workos.com/docs/integrations/keycloak-saml",
+            Self::MicrosoftOauth => "https://workos.com/docs/integrations/microsoft-oauth",
+            Self::MiniOrangeSaml => "https://workos.com/docs/integrations/mini-orange-saml",
+            Self::NetIqSaml => "https://workos.com/docs/integrations/net-iq-saml",
+            Self::OktaSaml => "https://workos.com/docs/integrations/okta-saml",
+            Self::OneLoginSaml => "https://workos.com/docs/integrations/onelogin-saml",
+            Self::OracleSaml => "https://workos.com/docs/integrations/oracle-saml",
+            Self::PingFederateSaml => "https://workos.com/docs/integrations/ping-federate-saml",
+            Self::PingOneSaml => "https://workos.com/docs/integrations/ping-one-saml",
+            Self::SalesforceSaml => "https://workos.com/docs/integrations/salesforce-saml",
+            Self::ShibbolethSaml => "https://workos.com/docs/integrations/shibboleth",
+            Self::SimpleSamlPhpSaml => "https://workos.com/docs/integrations/simple-saml-php-saml",
+            Self::VmwareSaml => "https://workos.com/docs/integrations/vmware-saml",
+        }
+    }
+
+    /// Returns whether an organization's IT admin can set up a connection of this type
+    /// themselves through the [Admin Portal](crate::admin_portal).
+    ///
+    /// OAuth connections are configured by the developer in the WorkOS Dashboard instead.
+    pub fn is_self_serve(&self) -> bool {
+        self.protocol() != ProviderProtocol::Oauth
+    }
+}
+
+impl KnownOrUnknown<ConnectionType, String> {
+    /// Returns the human-readable name of the connection type, or the raw value if the
+    /// connection type is unknown.
+    pub fn display_name(&self) -> &str {
+        match self {
+            KnownOrUnknown::Known(known) => known.display_name(),
+            KnownOrUnknown::Unknown(unknown) => unknown,
+        }
+    }
+
+    /// Returns the protocol family of the connection type, if it is known.
+    pub fn protocol(&self) -> Option<ProviderProtocol> {
+        match self {
+            KnownOrUnknown::Known(known) => Some(known.protocol()),
+            KnownOrUnknown::Unknown(_) => None,
+        }
+    }
+
+    /// Returns the URL of the WorkOS integration guide for the connection type, if it is
+    /// known.
+    pub fn docs_url(&self) -> Option<&'static str> {
+        match self {
+            KnownOrUnknown::Known(known) => Some(known.docs_url()),
+            KnownOrUnknown::Unknown(_) => None,
+        }
+    }
+
+    /// Returns whether the connection type is known and can be set up through the
+    /// [Admin Portal](crate::admin_portal).
+    pub fn is_self_serve(&self) -> bool {
+        match self {
+            KnownOrUnknown::Known(known) => known.is_self_serve(),
+            KnownOrUnknown::Unknown(_) => false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use serde_json::json;
 
-    use super::ConnectionType;
+    use super::*;
 
     #[test]
     fn it_properly_serializes_adp_oidc() {
@@ -187,4 +333,29 @@ mod test {
             ConnectionType::AdpOidc
         )
     }
+
+    #[test]
+    fn it_returns_the_catalog_metadata_of_a_connection_type() {
+        let connection_type = ConnectionType::OktaSaml;
+
+        assert_eq!(connection_type.display_name(), "Okta SAML");
+        assert_eq!(connection_type.protocol(), ProviderProtocol::Saml);
+        assert_eq!(
+            connection_type.docs_url(),
+            "https://workos.com/docs/integrations/okta-saml"
+        );
+        assert!(connection_type.is_self_serve());
+        assert!(!ConnectionType::GoogleOauth.is_self_serve());
+    }
+
+    #[test]
+    fn it_falls_back_to_the_raw_value_of_an_unknown_connection_type() {
+        let connection_type: KnownOrUnknown<ConnectionType, String> =
+            serde_json::from_str(&json!("NewProviderSAML").to_string()).unwrap();
+
+        assert_eq!(connection_type.display_name(), "NewProviderSAML");
+        assert_eq!(connection_type.protocol(), None);
+        assert_eq!(connection_type.docs_url(), None);
+        assert!(!connection_type.is_self_serve());
+    }
 }
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The tokens issued by an OAuth provider, e.g., Google or Microsoft, when a user
+/// authenticates through it.
+///
+/// These can be used to call the provider's APIs on behalf of the user.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OauthTokens {
+    /// The access token issued by the OAuth provider.
+    pub access_token: String,
+
+    /// The refresh token issued by the OAuth provider.
+    pub refresh_token: String,
+
+    /// The time at which the access token expires.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub expires_at: DateTime<Utc>,
+
+    /// The scopes granted to the access token.
+    pub scopes: Vec<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_oauth_tokens() {
+        let tokens: OauthTokens = serde_json::from_str(
+            &json!({
+                "access_token": "ya29.a0AfB_byC",
+                "refresh_token": "1//04dJ6KqZ",
+                "expires_at": 1735141800,
+                "scopes": [
+                    "https://www.googleapis.com/auth/userinfo.email",
+                    "openid"
+                ]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            tokens,
+            OauthTokens {
+                access_token: "ya29.a0AfB_byC".to_string(),
+                refresh_token: "1//04dJ6KqZ".to_string(),
+                expires_at: Utc.timestamp_opt(1735141800, 0).unwrap(),
+                scopes: vec![
+                    "https://www.googleapis.com/auth/userinfo.email".to_string(),
+                    "openid".to_string()
+                ],
+            }
+        )
+    }
+}
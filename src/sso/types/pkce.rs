@@ -0,0 +1,130 @@
+use std::fmt::Display;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::Serialize;
+
+use crate::{fill_random, sha256};
+
+/// A high-entropy secret generated by the client and sent when exchanging an
+/// authorization code.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct CodeVerifier(String);
+
+impl Display for CodeVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for CodeVerifier {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for CodeVerifier {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// The `S256` transformation of a [`CodeVerifier`], sent when initiating authorization.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct CodeChallenge(String);
+
+impl CodeChallenge {
+    /// Returns the `S256` code challenge for the given code verifier.
+    pub fn s256(code_verifier: &CodeVerifier) -> Self {
+        Self(URL_SAFE_NO_PAD.encode(sha256(code_verifier.0.as_bytes())))
+    }
+}
+
+impl Display for CodeChallenge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for CodeChallenge {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for CodeChallenge {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// A Proof Key for Code Exchange (PKCE) pair, for public clients that cannot hold the API key.
+///
+/// The [`code_challenge`](Self::code_challenge) is sent when building the authorization
+/// URL and the [`code_verifier`](Self::code_verifier) is sent in place of the client
+/// secret when exchanging the authorization code.
+///
+/// [RFC 7636](https://datatracker.ietf.org/doc/html/rfc7636)
+///
+/// # Examples
+///
+/// ```
+/// # use workos::sso::*;
+/// let pkce = Pkce::generate();
+///
+/// assert_eq!(pkce.code_challenge, CodeChallenge::s256(&pkce.code_verifier));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pkce {
+    /// The code verifier.
+    pub code_verifier: CodeVerifier,
+
+    /// The `S256` code challenge derived from the code verifier.
+    pub code_challenge: CodeChallenge,
+}
+
+impl Pkce {
+    /// Generates a new PKCE pair from a random 43-character code verifier.
+    pub fn generate() -> Self {
+        let mut bytes = [0; 32];
+        fill_random(&mut bytes);
+
+        Self::from_verifier(CodeVerifier(URL_SAFE_NO_PAD.encode(bytes)))
+    }
+
+    /// Returns the PKCE pair for an existing code verifier.
+    pub fn from_verifier(code_verifier: CodeVerifier) -> Self {
+        let code_challenge = CodeChallenge::s256(&code_verifier);
+
+        Self {
+            code_verifier,
+            code_challenge,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_derives_the_s256_code_challenge() {
+        let pkce = Pkce::from_verifier(CodeVerifier::from(
+            "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk",
+        ));
+
+        assert_eq!(
+            pkce.code_challenge,
+            CodeChallenge::from("E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM")
+        )
+    }
+
+    #[test]
+    fn it_generates_a_unique_code_verifier() {
+        let first = Pkce::generate();
+        let second = Pkce::generate();
+
+        assert_eq!(first.code_verifier.to_string().len(), 43);
+        assert_ne!(first.code_verifier, second.code_verifier);
+    }
+}
@@ -1,6 +1,8 @@
+use std::collections::HashMap;
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use super::{ConnectionId, ConnectionType};
 use crate::organizations::OrganizationId;
@@ -29,8 +31,8 @@ impl From<&str> for ProfileId {
 }
 
 /// [WorkOS Docs: Profile](https://workos.com/docs/reference/sso/profile)
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Profile {
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile<TCustomAttributes = HashMap<String, Value>> {
     /// The ID of the profile.
     pub id: ProfileId,
 
@@ -55,6 +57,151 @@ pub struct Profile {
     /// The user's last name.
     pub last_name: Option<String>,
 
+    /// The role of the user, when role assignment is configured for the connection.
+    pub role: Option<ProfileRole>,
+
+    /// The names of the groups the user belongs to in the Identity Provider, when group
+    /// mapping is configured for the connection.
+    pub groups: Option<Vec<String>>,
+
+    /// The custom attributes mapped from the Identity Provider.
+    ///
+    /// Defaults to an empty value when the response does not include them.
+    #[serde(default)]
+    pub custom_attributes: TCustomAttributes,
+
     /// The raw attributes received from the Identity Provider.
     pub raw_attributes: RawAttributes,
 }
+
+/// The role of a [`Profile`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileRole {
+    /// The slug of the role.
+    pub slug: String,
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+    use serde_json::{json, Value};
+
+    use super::{Profile, ProfileId, ProfileRole};
+    use crate::organizations::OrganizationId;
+    use crate::sso::{ConnectionId, ConnectionType};
+    use crate::{KnownOrUnknown, RawAttributes};
+
+    #[test]
+    fn it_deserializes_a_profile() {
+        let profile: Profile = serde_json::from_str(
+            &json!({
+                "object": "profile",
+                "id": "prof_01DMC79VCBZ0NY2099737PSVF1",
+                "connection_id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                "connection_type": "OktaSAML",
+                "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+                "email": "todd@example.com",
+                "first_name": "Todd",
+                "last_name": "Rundgren",
+                "idp_id": "00u1a0ufowBJlzPlk357",
+                "role": {
+                    "slug": "admin"
+                },
+                "groups": ["Engineering", "Admins"],
+                "custom_attributes": {
+                    "department": "Engineering"
+                },
+                "raw_attributes": {}
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let mut expected_custom_attributes = HashMap::new();
+        expected_custom_attributes.insert(
+            "department".to_string(),
+            Value::String("Engineering".to_string()),
+        );
+
+        assert_eq!(
+            profile,
+            Profile {
+                id: ProfileId::from("prof_01DMC79VCBZ0NY2099737PSVF1"),
+                connection_id: ConnectionId::from("conn_01E4ZCR3C56J083X43JQXF3JK5"),
+                organization_id: Some(OrganizationId::from("org_01EHWNCE74X7JSDV0X3SZ3KJNY")),
+                connection_type: KnownOrUnknown::Known(ConnectionType::OktaSaml),
+                idp_id: "00u1a0ufowBJlzPlk357".to_string(),
+                email: "todd@example.com".to_string(),
+                first_name: Some("Todd".to_string()),
+                last_name: Some("Rundgren".to_string()),
+                role: Some(ProfileRole {
+                    slug: "admin".to_string()
+                }),
+                groups: Some(vec!["Engineering".to_string(), "Admins".to_string()]),
+                custom_attributes: expected_custom_attributes,
+                raw_attributes: RawAttributes(HashMap::new()),
+            }
+        )
+    }
+
+    #[test]
+    fn it_deserializes_a_profile_with_a_provided_custom_attributes_type() {
+        #[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+        struct MyCustomAttributes {
+            pub department: String,
+        }
+
+        let profile: Profile<MyCustomAttributes> = serde_json::from_str(
+            &json!({
+                "object": "profile",
+                "id": "prof_01DMC79VCBZ0NY2099737PSVF1",
+                "connection_id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                "connection_type": "OktaSAML",
+                "organization_id": null,
+                "email": "todd@example.com",
+                "first_name": null,
+                "last_name": null,
+                "idp_id": "00u1a0ufowBJlzPlk357",
+                "custom_attributes": {
+                    "department": "Engineering"
+                },
+                "raw_attributes": {}
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            profile.custom_attributes,
+            MyCustomAttributes {
+                department: "Engineering".to_string()
+            }
+        );
+        assert_eq!(profile.role, None);
+        assert_eq!(profile.groups, None)
+    }
+
+    #[test]
+    fn it_deserializes_a_profile_without_custom_attributes() {
+        let profile: Profile = serde_json::from_str(
+            &json!({
+                "object": "profile",
+                "id": "prof_01DMC79VCBZ0NY2099737PSVF1",
+                "connection_id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                "connection_type": "OktaSAML",
+                "organization_id": null,
+                "email": "todd@example.com",
+                "first_name": null,
+                "last_name": null,
+                "idp_id": "00u1a0ufowBJlzPlk357",
+                "raw_attributes": {}
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert!(profile.custom_attributes.is_empty())
+    }
+}
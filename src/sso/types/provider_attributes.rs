@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+use crate::deserialize_one_or_many;
+
+/// The raw attributes of a [`Profile`](crate::sso::Profile) from an
+/// [Okta SAML](crate::sso::ConnectionType::OktaSaml) connection.
+///
+/// Decode with [`RawAttributes::decode`](crate::RawAttributes::decode).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OktaSamlAttributes {
+    /// The ID of the user in Okta.
+    pub id: Option<String>,
+
+    /// The email address of the user.
+    pub email: Option<String>,
+
+    /// The first name of the user.
+    pub first_name: Option<String>,
+
+    /// The last name of the user.
+    pub last_name: Option<String>,
+
+    /// The names of the Okta groups the user belongs to.
+    #[serde(deserialize_with = "deserialize_one_or_many")]
+    pub groups: Vec<String>,
+}
+
+/// The raw attributes of a [`Profile`](crate::sso::Profile) from an
+/// [Azure AD SAML](crate::sso::ConnectionType::AzureSaml) connection.
+///
+/// Azure AD sends its default claims under their full claim URIs.
+///
+/// Decode with [`RawAttributes::decode`](crate::RawAttributes::decode).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AzureSamlAttributes {
+    /// The object ID of the user in Azure AD.
+    #[serde(rename = "http://schemas.microsoft.com/identity/claims/objectidentifier")]
+    pub object_id: Option<String>,
+
+    /// The ID of the Azure AD tenant.
+    #[serde(rename = "http://schemas.microsoft.com/identity/claims/tenantid")]
+    pub tenant_id: Option<String>,
+
+    /// The user principal name of the user.
+    #[serde(rename = "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/name")]
+    pub user_principal_name: Option<String>,
+
+    /// The email address of the user.
+    #[serde(rename = "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/emailaddress")]
+    pub email: Option<String>,
+
+    /// The display name of the user.
+    #[serde(rename = "http://schemas.microsoft.com/identity/claims/displayname")]
+    pub display_name: Option<String>,
+
+    /// The given name of the user.
+    #[serde(rename = "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/givenname")]
+    pub given_name: Option<String>,
+
+    /// The surname of the user.
+    #[serde(rename = "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/surname")]
+    pub surname: Option<String>,
+
+    /// The object IDs of the Azure AD groups the user belongs to.
+    #[serde(
+        rename = "http://schemas.microsoft.com/ws/2008/06/identity/claims/groups",
+        deserialize_with = "deserialize_one_or_many"
+    )]
+    pub groups: Vec<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+    use crate::RawAttributes;
+
+    #[test]
+    fn it_decodes_okta_saml_attributes_with_a_single_group() {
+        let raw_attributes: RawAttributes = serde_json::from_str(
+            &json!({
+                "id": "00u1a0ufowBJlzPlk357",
+                "email": "todd@foo-corp.com",
+                "firstName": "Todd",
+                "groups": "Engineering"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            raw_attributes.decode::<OktaSamlAttributes>().unwrap(),
+            OktaSamlAttributes {
+                id: Some("00u1a0ufowBJlzPlk357".to_string()),
+                email: Some("todd@foo-corp.com".to_string()),
+                first_name: Some("Todd".to_string()),
+                last_name: None,
+                groups: vec!["Engineering".to_string()],
+            }
+        )
+    }
+
+    #[test]
+    fn it_decodes_azure_saml_attributes() {
+        let raw_attributes: RawAttributes = serde_json::from_str(
+            &json!({
+                "http://schemas.microsoft.com/identity/claims/objectidentifier": "8c6b0d5b-2f9c-4a4c-9d1f-6c1ed1c6b7a2",
+                "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/emailaddress": "todd@foo-corp.com",
+                "http://schemas.microsoft.com/ws/2008/06/identity/claims/groups": [
+                    "1f8f3b4e-8a1c-4a0e-9b8b-2d6f0c0b7e11",
+                    "a6a1f4f0-3c4e-4d7a-8f0f-5e2b9c3d1a22"
+                ]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let attributes = raw_attributes.decode::<AzureSamlAttributes>().unwrap();
+
+        assert_eq!(
+            attributes.object_id.as_deref(),
+            Some("8c6b0d5b-2f9c-4a4c-9d1f-6c1ed1c6b7a2")
+        );
+        assert_eq!(attributes.email.as_deref(), Some("todd@foo-corp.com"));
+        assert_eq!(attributes.given_name, None);
+        assert_eq!(attributes.groups.len(), 2);
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{fill_random, hmac_sha256, verify_hmac_sha256};
+
+/// A store that records which `state` nonces have already been used, so that a
+/// [`StateCodec`] can reject replayed callbacks.
+pub trait NonceStore: Send + Sync {
+    /// Marks the nonce as used until `expires_at`.
+    ///
+    /// Returns `true` if this is the first time the nonce is used and `false` if it was
+    /// already used.
+    fn consume(&self, nonce: &str, expires_at: DateTime<Utc>) -> bool;
+}
+
+/// A [`NonceStore`] that keeps used nonces in memory.
+///
+/// Nonces are forgotten once their `state` expires. When running more than one instance
+/// of a service, use a shared store instead.
+#[derive(Debug, Default)]
+pub struct InMemoryNonceStore {
+    nonces: Mutex<HashMap<String, DateTime<Utc>>>,
+}
+
+impl NonceStore for InMemoryNonceStore {
+    fn consume(&self, nonce: &str, expires_at: DateTime<Utc>) -> bool {
+        let mut nonces = self.nonces.lock().unwrap_or_else(|err| err.into_inner());
+
+        let now = Utc::now();
+        nonces.retain(|_, expires_at| *expires_at > now);
+
+        nonces.insert(nonce.to_string(), expires_at).is_none()
+    }
+}
+
+/// An error returned from [`StateCodec`].
+#[derive(Debug, Error)]
+pub enum StateCodecError {
+    /// The secret passed to [`StateCodec::new`] is shorter than
+    /// [`StateCodec::MIN_SECRET_LEN`] bytes.
+    #[error("state secret must be at least {min} bytes, got {len}", min = StateCodec::MIN_SECRET_LEN)]
+    SecretTooShort {
+        /// The length of the provided secret, in bytes.
+        len: usize,
+    },
+
+    /// The `state` is not in the format produced by [`StateCodec::encode`].
+    #[error("malformed state")]
+    Malformed,
+
+    /// The signature of the `state` does not match its contents.
+    #[error("state signature mismatch")]
+    Tampered,
+
+    /// The `state` has expired.
+    #[error("state expired at {0}")]
+    Expired(DateTime<Utc>),
+
+    /// The `state` has already been used.
+    #[error("state has already been used")]
+    Replayed,
+
+    /// The data could not be serialized or deserialized.
+    #[error("failed to (de)serialize state data")]
+    Serialization(#[from] serde_json::Error),
+}
+
+#[derive(Serialize, Deserialize)]
+struct StatePayload<T> {
+    data: T,
+    nonce: String,
+    exp: i64,
+}
+
+/// Encodes caller-defined data into a signed, expiring `state` parameter for CSRF
+/// protection, and verifies it on callback.
+///
+/// The `state` carries the data, a random nonce and an expiry, signed with HMAC-SHA256.
+/// Each `state` can only be decoded once.
+///
+/// # Examples
+///
+/// ```
+/// # use workos::sso::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct ReturnTo {
+///     path: String,
+/// }
+///
+/// # fn run() -> Result<(), StateCodecError> {
+/// let codec = StateCodec::new(b"a-secret-of-at-least-32-bytes-long")?;
+///
+/// let state = codec.encode(&ReturnTo {
+///     path: "/dashboard".to_string(),
+/// })?;
+///
+/// // Pass `state` to `GetAuthorizationUrlParams`, then on callback:
+/// let ReturnTo { path } = codec.decode(&state)?;
+/// # assert_eq!(path, "/dashboard");
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub struct StateCodec {
+    secret: Vec<u8>,
+    ttl: Duration,
+    nonce_store: Box<dyn NonceStore>,
+}
+
+impl StateCodec {
+    /// The minimum length of the signing secret, in bytes.
+    pub const MIN_SECRET_LEN: usize = 32;
+
+    /// Returns a new [`StateCodec`] that signs with the given secret.
+    ///
+    /// The secret must be at least [`StateCodec::MIN_SECRET_LEN`] bytes of random data.
+    /// States expire after 10 minutes and used nonces are kept in an
+    /// [`InMemoryNonceStore`].
+    pub fn new(secret: &[u8]) -> Result<Self, StateCodecError> {
+        if secret.len() < Self::MIN_SECRET_LEN {
+            return Err(StateCodecError::SecretTooShort { len: secret.len() });
+        }
+
+        Ok(Self {
+            secret: secret.to_vec(),
+            ttl: Duration::minutes(10),
+            nonce_store: Box::new(InMemoryNonceStore::default()),
+        })
+    }
+
+    /// Sets how long an encoded `state` remains valid.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets the store used to reject replayed states.
+    pub fn with_nonce_store(mut self, nonce_store: impl NonceStore + 'static) -> Self {
+        self.nonce_store = Box::new(nonce_store);
+        self
+    }
+
+    /// Encodes and signs `data` into a `state` parameter.
+    pub fn encode<T: Serialize>(&self, data: &T) -> Result<String, StateCodecError> {
+        let mut nonce = [0; 16];
+        fill_random(&mut nonce);
+
+        let payload = StatePayload {
+            data,
+            nonce: URL_SAFE_NO_PAD.encode(nonce),
+            exp: (Utc::now() + self.ttl).timestamp(),
+        };
+        let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&payload)?);
+        let signature = URL_SAFE_NO_PAD.encode(hmac_sha256(&self.secret, payload.as_bytes()));
+
+        Ok(format!("{payload}.{signature}"))
+    }
+
+    /// Verifies a `state` parameter and returns the data it was encoded from.
+    ///
+    /// The signature is checked first, then the expiry, then whether the `state` was
+    /// already used.
+    pub fn decode<T: DeserializeOwned>(&self, state: &str) -> Result<T, StateCodecError> {
+        let (payload, signature) = state.split_once('.').ok_or(StateCodecError::Malformed)?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| StateCodecError::Malformed)?;
+
+        if !verify_hmac_sha256(&self.secret, payload.as_bytes(), &signature) {
+            return Err(StateCodecError::Tampered);
+        }
+
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| StateCodecError::Malformed)?;
+        let StatePayload { data, nonce, exp } =
+            serde_json::from_slice::<StatePayload<T>>(&payload)?;
+
+        let expires_at = Utc
+            .timestamp_opt(exp, 0)
+            .single()
+            .ok_or(StateCodecError::Malformed)?;
+        if Utc::now() >= expires_at {
+            return Err(StateCodecError::Expired(expires_at));
+        }
+
+        if !self.nonce_store.consume(&nonce, expires_at) {
+            return Err(StateCodecError::Replayed);
+        }
+
+        Ok(data)
+    }
+}
+
+impl std::fmt::Debug for StateCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StateCodec")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct ReturnTo {
+        path: String,
+    }
+
+    const SECRET: &[u8] = b"8f2c6e0b4a1d9f7e3c5b2a8d6f4e1c9b";
+
+    fn return_to() -> ReturnTo {
+        ReturnTo {
+            path: "/dashboard".to_string(),
+        }
+    }
+
+    #[test]
+    fn it_round_trips_the_state_data() {
+        let codec = StateCodec::new(SECRET).unwrap();
+
+        let state = codec.encode(&return_to()).unwrap();
+
+        assert_eq!(codec.decode::<ReturnTo>(&state).unwrap(), return_to())
+    }
+
+    #[test]
+    fn it_rejects_a_tampered_state() {
+        let codec = StateCodec::new(SECRET).unwrap();
+
+        let state = codec.encode(&return_to()).unwrap();
+        let (_, signature) = state.split_once('.').unwrap();
+        let forged_payload = URL_SAFE_NO_PAD.encode(
+            serde_json::to_vec(&StatePayload {
+                data: ReturnTo {
+                    path: "https://evil.example.com".to_string(),
+                },
+                nonce: "nonce".to_string(),
+                exp: i64::MAX / 1000,
+            })
+            .unwrap(),
+        );
+
+        assert_matches!(
+            codec.decode::<ReturnTo>(&format!("{forged_payload}.{signature}")),
+            Err(StateCodecError::Tampered)
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_state_signed_with_another_secret() {
+        let state = StateCodec::new(b"3d9a7c1e5b8f2d6a4c0e9b7f1a3d5c8e")
+            .unwrap()
+            .encode(&return_to())
+            .unwrap();
+
+        assert_matches!(
+            StateCodec::new(SECRET).unwrap().decode::<ReturnTo>(&state),
+            Err(StateCodecError::Tampered)
+        )
+    }
+
+    #[test]
+    fn it_rejects_an_expired_state() {
+        let codec = StateCodec::new(SECRET)
+            .unwrap()
+            .with_ttl(Duration::seconds(-1));
+
+        let state = codec.encode(&return_to()).unwrap();
+
+        assert_matches!(
+            codec.decode::<ReturnTo>(&state),
+            Err(StateCodecError::Expired(_))
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_replayed_state() {
+        let codec = StateCodec::new(SECRET).unwrap();
+
+        let state = codec.encode(&return_to()).unwrap();
+        codec.decode::<ReturnTo>(&state).unwrap();
+
+        assert_matches!(
+            codec.decode::<ReturnTo>(&state),
+            Err(StateCodecError::Replayed)
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_malformed_state() {
+        let codec = StateCodec::new(SECRET).unwrap();
+
+        assert_matches!(
+            codec.decode::<ReturnTo>("not-a-state"),
+            Err(StateCodecError::Malformed)
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_short_secret() {
+        assert_matches!(
+            StateCodec::new(b"secret"),
+            Err(StateCodecError::SecretTooShort { len: 6 })
+        )
+    }
+}
@@ -1,9 +1,39 @@
+mod authenticate_with_code;
+mod create_organization_membership;
+mod create_user;
+mod deactivate_organization_membership;
 mod delete_organization_membership;
+mod get_device_authorization;
 mod get_organization_membership;
+mod get_user_by_external_id;
+mod get_user_identities;
+mod import_users;
 mod list_organization_memberships;
+mod list_sessions;
+mod list_users;
+mod poll_device_token;
+mod provision_from_profile;
+mod revoke_session;
 mod send_invitation;
+mod update_organization_membership;
+mod update_user;
 
+pub use authenticate_with_code::*;
+pub use create_organization_membership::*;
+pub use create_user::*;
+pub use deactivate_organization_membership::*;
 pub use delete_organization_membership::*;
+pub use get_device_authorization::*;
 pub use get_organization_membership::*;
+pub use get_user_by_external_id::*;
+pub use get_user_identities::*;
+pub use import_users::*;
 pub use list_organization_memberships::*;
+pub use list_sessions::*;
+pub use list_users::*;
+pub use poll_device_token::*;
+pub use provision_from_profile::*;
+pub use revoke_session::*;
 pub use send_invitation::*;
+pub use update_organization_membership::*;
+pub use update_user::*;
@@ -0,0 +1,391 @@
+use async_trait::async_trait;
+use reqwest::{Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::sso::{AuthorizationCode, ClientId, CodeVerifier};
+use crate::user_management::{AuthenticationResponse, UserManagement};
+use crate::{ResponseExt, WorkOsError, WorkOsResult};
+
+/// The parameters for [`AuthenticateWithCode`].
+#[derive(Debug)]
+pub struct AuthenticateWithCodeParams<'a> {
+    /// The client ID corresponding to the environment that authentication was initiated
+    /// from.
+    pub client_id: &'a ClientId,
+
+    /// The authorization code to exchange.
+    pub code: &'a AuthorizationCode,
+
+    /// The PKCE code verifier for the code challenge that was used to initiate
+    /// authentication.
+    ///
+    /// When provided, the code verifier is sent instead of the API key as the client
+    /// secret.
+    pub code_verifier: Option<&'a CodeVerifier>,
+
+    /// The IP address of the request from the user who is attempting to authenticate.
+    pub ip_address: Option<&'a str>,
+
+    /// The user agent of the request from the user who is attempting to authenticate.
+    pub user_agent: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+struct AuthenticateWithCodeBody<'a> {
+    client_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_secret: Option<String>,
+    grant_type: &'a str,
+    code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code_verifier: Option<&'a CodeVerifier>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ip_address: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_agent: Option<&'a str>,
+}
+
+/// An error returned when a user fails to authenticate.
+#[derive(Debug, Error, Deserialize)]
+#[error("{error}: {error_description}")]
+pub struct AuthenticateError {
+    /// The error code of the error that occurred.
+    pub error: String,
+
+    /// The description of the error.
+    pub error_description: String,
+}
+
+#[async_trait]
+pub(crate) trait HandleAuthenticateError
+where
+    Self: Sized,
+{
+    async fn handle_authenticate_error(self) -> WorkOsResult<Self, AuthenticateError>;
+}
+
+#[async_trait]
+impl HandleAuthenticateError for Response {
+    async fn handle_authenticate_error(self) -> WorkOsResult<Self, AuthenticateError> {
+        match self.error_for_status_ref() {
+            Ok(_) => Ok(self),
+            Err(err) => match err.status() {
+                Some(StatusCode::BAD_REQUEST) => {
+                    let error = self.json::<AuthenticateError>().await?;
+
+                    Err(match error.error.as_str() {
+                        "invalid_client" | "unauthorized_client" => WorkOsError::Unauthorized,
+                        _ => WorkOsError::Operation(error),
+                    })
+                }
+                _ => Err(WorkOsError::RequestError(err)),
+            },
+        }
+    }
+}
+
+/// [WorkOS Docs: Authenticate with Code](https://workos.com/docs/reference/user-management/authentication/code)
+#[async_trait]
+pub trait AuthenticateWithCode {
+    /// Authenticates a user using an authorization code from AuthKit or an OAuth provider.
+    ///
+    /// [WorkOS Docs: Authenticate with Code](https://workos.com/docs/reference/user-management/authentication/code)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos::WorkOsResult;
+    /// # use workos::sso::{AuthorizationCode, ClientId};
+    /// # use workos::user_management::*;
+    /// use workos::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), AuthenticateError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let AuthenticationResponse { user, .. } = workos
+    ///     .user_management()
+    ///     .authenticate_with_code(&AuthenticateWithCodeParams {
+    ///         client_id: &ClientId::from("client_123456789"),
+    ///         code: &AuthorizationCode::from("01E2RJ4C05B52KKZ8FSRDAP23J"),
+    ///         code_verifier: None,
+    ///         ip_address: Some("192.0.2.1"),
+    ///         user_agent: None,
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn authenticate_with_code(
+        &self,
+        params: &AuthenticateWithCodeParams<'_>,
+    ) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
+}
+
+#[async_trait]
+impl AuthenticateWithCode for UserManagement<'_> {
+    async fn authenticate_with_code(
+        &self,
+        params: &AuthenticateWithCodeParams<'_>,
+    ) -> WorkOsResult<AuthenticationResponse, AuthenticateError> {
+        let &AuthenticateWithCodeParams {
+            client_id,
+            code,
+            code_verifier,
+            ip_address,
+            user_agent,
+        } = params;
+
+        let url = self
+            .workos
+            .base_url()
+            .join("/user_management/authenticate")?;
+        let body = AuthenticateWithCodeBody {
+            client_id: client_id.to_string(),
+            client_secret: code_verifier
+                .is_none()
+                .then(|| self.workos.key().to_string()),
+            grant_type: "authorization_code",
+            code: code.to_string(),
+            code_verifier,
+            ip_address,
+            user_agent,
+        };
+        let authenticate_response = self
+            .workos
+            .client()
+            .post(url)
+            .json(&body)
+            .send()
+            .await?
+            .handle_unauthorized_error()?
+            .handle_authenticate_error()
+            .await?
+            .json::<AuthenticationResponse>()
+            .await?;
+
+        Ok(authenticate_response)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::user_management::{AuthenticationMethod, UserId};
+    use crate::{ApiKey, KnownOrUnknown, WorkOs};
+
+    #[tokio::test]
+    async fn it_calls_the_authenticate_endpoint_with_the_code_grant() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock("POST", "/user_management/authenticate")
+            .match_body(Matcher::Json(json!({
+                "client_id": "client_123456789",
+                "client_secret": "sk_example_123456789",
+                "grant_type": "authorization_code",
+                "code": "abc123"
+            })))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "user": {
+                        "object": "user",
+                        "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                        "email": "marcelina.davis@example.com",
+                        "first_name": "Marcelina",
+                        "last_name": "Davis",
+                        "email_verified": true,
+                        "profile_picture_url": null,
+                        "last_sign_in_at": null,
+                        "external_id": null,
+                        "metadata": {},
+                        "created_at": "2021-06-25T19:07:33.155Z",
+                        "updated_at": "2021-06-25T19:07:33.155Z"
+                    },
+                    "organization_id": "org_01H945H0YD4F97JN9MATX7BYAG",
+                    "access_token": "eyJhb.nNzb19vaWRjX2tleV9.lc5Uk4yWVk5In0",
+                    "refresh_token": "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                    "authentication_method": "GoogleOAuth",
+                    "oauth_tokens": {
+                        "access_token": "ya29.a0AfB_byC",
+                        "refresh_token": "1//04dJ6KqZ",
+                        "expires_at": 1735141800,
+                        "scopes": ["openid"]
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = workos
+            .user_management()
+            .authenticate_with_code(&AuthenticateWithCodeParams {
+                client_id: &ClientId::from("client_123456789"),
+                code: &AuthorizationCode::from("abc123"),
+                code_verifier: None,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.user.id,
+            UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5")
+        );
+        assert_eq!(
+            response.authentication_method,
+            Some(KnownOrUnknown::Known(AuthenticationMethod::GoogleOauth))
+        );
+        assert_eq!(
+            response.oauth_tokens.map(|tokens| tokens.access_token),
+            Some("ya29.a0AfB_byC".to_string())
+        )
+    }
+
+    #[tokio::test]
+    async fn it_sends_the_code_verifier_instead_of_the_client_secret() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock("POST", "/user_management/authenticate")
+            .match_body(Matcher::Json(json!({
+                "client_id": "client_123456789",
+                "grant_type": "authorization_code",
+                "code": "abc123",
+                "code_verifier": "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"
+            })))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "user": {
+                        "object": "user",
+                        "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                        "email": "marcelina.davis@example.com",
+                        "first_name": "Marcelina",
+                        "last_name": "Davis",
+                        "email_verified": true,
+                        "profile_picture_url": null,
+                        "last_sign_in_at": null,
+                        "external_id": null,
+                        "metadata": {},
+                        "created_at": "2021-06-25T19:07:33.155Z",
+                        "updated_at": "2021-06-25T19:07:33.155Z"
+                    },
+                    "organization_id": null,
+                    "access_token": "eyJhb.nNzb19vaWRjX2tleV9.lc5Uk4yWVk5In0",
+                    "refresh_token": "yAjhKk123NLIjdrBdGZPf8pLIDvK"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = workos
+            .user_management()
+            .authenticate_with_code(&AuthenticateWithCodeParams {
+                client_id: &ClientId::from("client_123456789"),
+                code: &AuthorizationCode::from("abc123"),
+                code_verifier: Some(&CodeVerifier::from(
+                    "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk",
+                )),
+                ip_address: None,
+                user_agent: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.user.id,
+            UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_the_authorization_code_is_invalid() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(400)
+            .with_body(
+                json!({
+                    "error": "invalid_grant",
+                    "error_description": "The code 'abc123' has expired or is invalid."
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let result = workos
+            .user_management()
+            .authenticate_with_code(&AuthenticateWithCodeParams {
+                client_id: &ClientId::from("client_123456789"),
+                code: &AuthorizationCode::from("abc123"),
+                code_verifier: None,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(AuthenticateError { error, .. })) if error == "invalid_grant"
+        )
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_unauthorized_error_with_an_invalid_client() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(400)
+            .with_body(
+                json!({
+                    "error": "invalid_client",
+                    "error_description": "Invalid client ID."
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let result = workos
+            .user_management()
+            .authenticate_with_code(&AuthenticateWithCodeParams {
+                client_id: &ClientId::from("client_123456789"),
+                code: &AuthorizationCode::from("abc123"),
+                code_verifier: None,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await;
+
+        assert_matches!(result, Err(WorkOsError::Unauthorized))
+    }
+}
@@ -0,0 +1,146 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::sso::ClientId;
+use crate::user_management::{DeviceAuthorization, UserManagement};
+use crate::{ResponseExt, WorkOsError, WorkOsResult};
+
+/// An error returned from [`GetDeviceAuthorization`].
+#[derive(Debug, Error)]
+pub enum GetDeviceAuthorizationError {}
+
+impl From<GetDeviceAuthorizationError> for WorkOsError<GetDeviceAuthorizationError> {
+    fn from(err: GetDeviceAuthorizationError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+/// [WorkOS Docs: CLI Auth](https://workos.com/docs/user-management/cli-auth)
+#[async_trait]
+pub trait GetDeviceAuthorization {
+    /// Starts an OAuth 2.0 Device Authorization Grant, e.g., to sign in from a CLI.
+    ///
+    /// Show the returned [`user_code`](DeviceAuthorization::user_code) and
+    /// [`verification_uri`](DeviceAuthorization::verification_uri) to the user, then use
+    /// [`PollDeviceToken`](crate::user_management::PollDeviceToken) to wait for them to
+    /// authorize the device.
+    ///
+    /// [WorkOS Docs: CLI Auth](https://workos.com/docs/user-management/cli-auth)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos::WorkOsResult;
+    /// # use workos::sso::ClientId;
+    /// # use workos::user_management::*;
+    /// use workos::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), GetDeviceAuthorizationError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let authorization = workos
+    ///     .user_management()
+    ///     .get_device_authorization(&ClientId::from("client_123456789"))
+    ///     .await?;
+    ///
+    /// println!(
+    ///     "Visit {} and enter the code {}",
+    ///     authorization.verification_uri, authorization.user_code
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_device_authorization(
+        &self,
+        client_id: &ClientId,
+    ) -> WorkOsResult<DeviceAuthorization, GetDeviceAuthorizationError>;
+}
+
+#[async_trait]
+impl GetDeviceAuthorization for UserManagement<'_> {
+    async fn get_device_authorization(
+        &self,
+        client_id: &ClientId,
+    ) -> WorkOsResult<DeviceAuthorization, GetDeviceAuthorizationError> {
+        let url = self
+            .workos
+            .base_url()
+            .join("/user_management/authorize/device")?;
+        let params = [("client_id", client_id.to_string())];
+        let authorization = self
+            .workos
+            .client()
+            .post(url)
+            .form(&params)
+            .send()
+            .await?
+            .handle_unauthorized_or_generic_error()?
+            .json::<DeviceAuthorization>()
+            .await?;
+
+        Ok(authorization)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::user_management::DeviceCode;
+    use crate::{ApiKey, WorkOs};
+
+    #[tokio::test]
+    async fn it_calls_the_device_authorization_endpoint() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock("POST", "/user_management/authorize/device")
+            .match_body(Matcher::UrlEncoded(
+                "client_id".to_string(),
+                "client_123456789".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "device_code": "CVE2wOfIFK4vhmiDBntpX9s8KT2f0qngpWYL0LGy9HxYgBRXUKIUkZB9BgIFho5h",
+                    "user_code": "BCDF-GHJK",
+                    "verification_uri": "https://foo-corp.authkit.app/device",
+                    "verification_uri_complete": "https://foo-corp.authkit.app/device?user_code=BCDF-GHJK",
+                    "expires_in": 300,
+                    "interval": 5
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let authorization = workos
+            .user_management()
+            .get_device_authorization(&ClientId::from("client_123456789"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            authorization,
+            DeviceAuthorization {
+                device_code: DeviceCode::from(
+                    "CVE2wOfIFK4vhmiDBntpX9s8KT2f0qngpWYL0LGy9HxYgBRXUKIUkZB9BgIFho5h"
+                ),
+                user_code: "BCDF-GHJK".to_string(),
+                verification_uri: "https://foo-corp.authkit.app/device".to_string(),
+                verification_uri_complete: Some(
+                    "https://foo-corp.authkit.app/device?user_code=BCDF-GHJK".to_string()
+                ),
+                expires_in: 300,
+                interval: 5,
+            }
+        )
+    }
+}
@@ -4,6 +4,7 @@ use thiserror::Error;
 
 use crate::organizations::OrganizationId;
 use crate::user_management::types::OrganizationMembership;
+use crate::user_management::UserId;
 use crate::{PaginatedList, PaginationParams, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`ListOrganizationMemberships`].
@@ -12,6 +13,10 @@ pub struct ListOrganizationMembershipsParams<'a> {
     /// The ID of the organization to list memberships for.
     pub organization_id: &'a OrganizationId,
 
+    /// The ID of the user to list memberships for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<&'a UserId>,
+
     /// The pagination parameters to use when listing organization memberships.
     #[serde(flatten)]
     pub pagination: PaginationParams<'a>,
@@ -117,6 +122,7 @@ mod test {
             .user_management()
             .list_organization_memberships(&ListOrganizationMembershipsParams {
                 organization_id: &OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"),
+                user_id: None,
                 pagination: Default::default(),
             })
             .await
@@ -132,4 +138,51 @@ mod test {
             Some("org_membership_01EJBGJT2PC6638TN5Y380M40Z".to_string())
         );
     }
+
+    #[tokio::test]
+    async fn it_filters_the_organization_memberships_by_user() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock("GET", "/user_management/organization_memberships")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(
+                    "organization_id".to_string(),
+                    "org_01EHZNVPK3SFK441A1RGBFSHRT".to_string(),
+                ),
+                Matcher::UrlEncoded(
+                    "user_id".to_string(),
+                    "user_01EHZNVPK3SFK441A1RGBFSHRT".to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let paginated_list = workos
+            .user_management()
+            .list_organization_memberships(&ListOrganizationMembershipsParams {
+                organization_id: &OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"),
+                user_id: Some(&UserId::from("user_01EHZNVPK3SFK441A1RGBFSHRT")),
+                pagination: Default::default(),
+            })
+            .await
+            .unwrap();
+
+        assert!(paginated_list.data.is_empty());
+    }
 }
@@ -0,0 +1,404 @@
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::sso::ClientId;
+use crate::user_management::{
+    AuthenticateError, AuthenticationResponse, DeviceAuthorization, DeviceCode,
+    HandleAuthenticateError, UserManagement,
+};
+use crate::{ResponseExt, WorkOsError, WorkOsResult};
+
+/// The amount of time by which the polling interval is increased when WorkOS responds with
+/// `slow_down`, as specified by [RFC 8628](https://datatracker.ietf.org/doc/html/rfc8628#section-3.5).
+const SLOW_DOWN_INCREMENT: Duration = Duration::from_secs(5);
+
+/// The parameters for [`PollDeviceToken`].
+#[derive(Debug)]
+pub struct PollDeviceTokenParams<'a> {
+    /// The client ID that was used to start the device authorization.
+    pub client_id: &'a ClientId,
+
+    /// The device authorization returned from
+    /// [`GetDeviceAuthorization`](crate::user_management::GetDeviceAuthorization).
+    pub device_authorization: &'a DeviceAuthorization,
+}
+
+#[derive(Debug, Serialize)]
+struct DeviceCodeBody<'a> {
+    client_id: String,
+    grant_type: &'a str,
+    device_code: &'a DeviceCode,
+}
+
+/// An error returned from [`PollDeviceToken`].
+#[derive(Debug, Error)]
+pub enum PollDeviceTokenError {
+    /// The device code expired before the user authorized the device.
+    #[error("device code expired")]
+    Expired,
+
+    /// The user denied the authorization request.
+    #[error("access denied")]
+    AccessDenied,
+
+    /// The authentication failed for another reason.
+    #[error(transparent)]
+    Authenticate(AuthenticateError),
+}
+
+impl From<PollDeviceTokenError> for WorkOsError<PollDeviceTokenError> {
+    fn from(err: PollDeviceTokenError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+/// [WorkOS Docs: CLI Auth](https://workos.com/docs/user-management/cli-auth)
+#[async_trait]
+pub trait PollDeviceToken {
+    /// Polls for the result of a device authorization until the user authorizes the device,
+    /// denies the request, or the device code expires.
+    ///
+    /// The polling interval starts at [`DeviceAuthorization::polling_interval`] and is increased
+    /// whenever WorkOS responds with `slow_down`.
+    ///
+    /// [WorkOS Docs: CLI Auth](https://workos.com/docs/user-management/cli-auth)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos::WorkOsResult;
+    /// # use workos::sso::ClientId;
+    /// # use workos::user_management::*;
+    /// use workos::{ApiKey, WorkOs};
+    ///
+    /// # async fn run(device_authorization: DeviceAuthorization) -> WorkOsResult<(), PollDeviceTokenError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let AuthenticationResponse { user, .. } = workos
+    ///     .user_management()
+    ///     .poll_device_token(&PollDeviceTokenParams {
+    ///         client_id: &ClientId::from("client_123456789"),
+    ///         device_authorization: &device_authorization,
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn poll_device_token(
+        &self,
+        params: &PollDeviceTokenParams<'_>,
+    ) -> WorkOsResult<AuthenticationResponse, PollDeviceTokenError>;
+}
+
+#[async_trait]
+impl PollDeviceToken for UserManagement<'_> {
+    async fn poll_device_token(
+        &self,
+        params: &PollDeviceTokenParams<'_>,
+    ) -> WorkOsResult<AuthenticationResponse, PollDeviceTokenError> {
+        let &PollDeviceTokenParams {
+            client_id,
+            device_authorization,
+        } = params;
+
+        let deadline = Instant::now() + device_authorization.lifetime();
+        let mut interval = device_authorization.polling_interval();
+
+        loop {
+            tokio::time::sleep(interval).await;
+
+            match request_device_token(self, client_id, &device_authorization.device_code).await {
+                Ok(response) => return Ok(response),
+                Err(WorkOsError::Operation(error)) => match error.error.as_str() {
+                    "authorization_pending" => {}
+                    "slow_down" => interval += SLOW_DOWN_INCREMENT,
+                    "expired_token" => return Err(PollDeviceTokenError::Expired.into()),
+                    "access_denied" => return Err(PollDeviceTokenError::AccessDenied.into()),
+                    _ => return Err(PollDeviceTokenError::Authenticate(error).into()),
+                },
+                Err(WorkOsError::Unauthorized) => return Err(WorkOsError::Unauthorized),
+                Err(WorkOsError::UrlParseError(err)) => {
+                    return Err(WorkOsError::UrlParseError(err))
+                }
+                Err(WorkOsError::RequestError(err)) => return Err(WorkOsError::RequestError(err)),
+            }
+
+            if Instant::now() >= deadline {
+                return Err(PollDeviceTokenError::Expired.into());
+            }
+        }
+    }
+}
+
+async fn request_device_token(
+    user_management: &UserManagement<'_>,
+    client_id: &ClientId,
+    device_code: &DeviceCode,
+) -> WorkOsResult<AuthenticationResponse, AuthenticateError> {
+    let url = user_management
+        .workos
+        .base_url()
+        .join("/user_management/authenticate")?;
+    let body = DeviceCodeBody {
+        client_id: client_id.to_string(),
+        grant_type: "urn:ietf:params:oauth:grant-type:device_code",
+        device_code,
+    };
+    let authenticate_response = user_management
+        .workos
+        .client()
+        .post(url)
+        .json(&body)
+        .send()
+        .await?
+        .handle_unauthorized_error()?
+        .handle_authenticate_error()
+        .await?
+        .json::<AuthenticationResponse>()
+        .await?;
+
+    Ok(authenticate_response)
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::user_management::UserId;
+    use crate::{ApiKey, WorkOs};
+
+    fn device_authorization() -> DeviceAuthorization {
+        DeviceAuthorization {
+            device_code: DeviceCode::from(
+                "CVE2wOfIFK4vhmiDBntpX9s8KT2f0qngpWYL0LGy9HxYgBRXUKIUkZB9BgIFho5h",
+            ),
+            user_code: "BCDF-GHJK".to_string(),
+            verification_uri: "https://foo-corp.authkit.app/device".to_string(),
+            verification_uri_complete: None,
+            expires_in: 300,
+            interval: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_polls_until_the_user_authorizes_the_device() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let pending_mock = server
+            .mock("POST", "/user_management/authenticate")
+            .match_body(Matcher::Json(json!({
+                "client_id": "client_123456789",
+                "grant_type": "urn:ietf:params:oauth:grant-type:device_code",
+                "device_code": "CVE2wOfIFK4vhmiDBntpX9s8KT2f0qngpWYL0LGy9HxYgBRXUKIUkZB9BgIFho5h"
+            })))
+            .with_status(400)
+            .with_body(
+                json!({
+                    "error": "authorization_pending",
+                    "error_description": "The authorization request is still pending."
+                })
+                .to_string(),
+            )
+            .expect(2)
+            .create_async()
+            .await;
+
+        let authenticated_mock = server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "user": {
+                        "object": "user",
+                        "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                        "email": "marcelina.davis@example.com",
+                        "first_name": "Marcelina",
+                        "last_name": "Davis",
+                        "email_verified": true,
+                        "profile_picture_url": null,
+                        "last_sign_in_at": null,
+                        "external_id": null,
+                        "metadata": {},
+                        "created_at": "2021-06-25T19:07:33.155Z",
+                        "updated_at": "2021-06-25T19:07:33.155Z"
+                    },
+                    "organization_id": null,
+                    "access_token": "eyJhb.nNzb19vaWRjX2tleV9.lc5Uk4yWVk5In0",
+                    "refresh_token": "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                    "authentication_method": "Password"
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let response = workos
+            .user_management()
+            .poll_device_token(&PollDeviceTokenParams {
+                client_id: &ClientId::from("client_123456789"),
+                device_authorization: &device_authorization(),
+            })
+            .await
+            .unwrap();
+
+        pending_mock.assert_async().await;
+        authenticated_mock.assert_async().await;
+        assert_eq!(
+            response.user.id,
+            UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5")
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn it_increases_the_polling_interval_when_asked_to_slow_down() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let slow_down_mock = server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(400)
+            .with_body(
+                json!({
+                    "error": "slow_down",
+                    "error_description": "The device is polling too frequently."
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let authenticated_mock = server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "user": {
+                        "object": "user",
+                        "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                        "email": "marcelina.davis@example.com",
+                        "first_name": "Marcelina",
+                        "last_name": "Davis",
+                        "email_verified": true,
+                        "profile_picture_url": null,
+                        "last_sign_in_at": null,
+                        "external_id": null,
+                        "metadata": {},
+                        "created_at": "2021-06-25T19:07:33.155Z",
+                        "updated_at": "2021-06-25T19:07:33.155Z"
+                    },
+                    "organization_id": null,
+                    "access_token": "eyJhb.nNzb19vaWRjX2tleV9.lc5Uk4yWVk5In0",
+                    "refresh_token": "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                    "authentication_method": "Password"
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let started_at = tokio::time::Instant::now();
+        let response = workos
+            .user_management()
+            .poll_device_token(&PollDeviceTokenParams {
+                client_id: &ClientId::from("client_123456789"),
+                device_authorization: &device_authorization(),
+            })
+            .await
+            .unwrap();
+
+        slow_down_mock.assert_async().await;
+        authenticated_mock.assert_async().await;
+        assert!(started_at.elapsed() >= SLOW_DOWN_INCREMENT);
+        assert_eq!(
+            response.user.id,
+            UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_the_device_code_expires() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(400)
+            .with_body(
+                json!({
+                    "error": "expired_token",
+                    "error_description": "The device code has expired."
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let result = workos
+            .user_management()
+            .poll_device_token(&PollDeviceTokenParams {
+                client_id: &ClientId::from("client_123456789"),
+                device_authorization: &device_authorization(),
+            })
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(PollDeviceTokenError::Expired))
+        )
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_the_user_denies_access() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _mock = server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(400)
+            .with_body(
+                json!({
+                    "error": "access_denied",
+                    "error_description": "The user denied the request."
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let result = workos
+            .user_management()
+            .poll_device_token(&PollDeviceTokenParams {
+                client_id: &ClientId::from("client_123456789"),
+                device_authorization: &device_authorization(),
+            })
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(PollDeviceTokenError::AccessDenied))
+        )
+    }
+}
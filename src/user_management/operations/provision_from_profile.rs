@@ -0,0 +1,870 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::organizations::{DomainPolicy, DomainPolicyViolation, GetOrganization, OrganizationId};
+use crate::sso::Profile;
+use crate::user_management::{
+    CreateOrganizationMembership, CreateOrganizationMembershipParams, CreateUser, CreateUserParams,
+    ListOrganizationMemberships, ListOrganizationMembershipsParams, ListUsers, ListUsersParams,
+    OrganizationMembership, OrganizationMembershipStatus, User, UserId, UserManagement,
+};
+use crate::{WorkOsError, WorkOsResult};
+
+/// A resource returned from [`ProvisionFromProfile`], which was either created or
+/// already existed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Provisioned<T> {
+    /// The resource was created.
+    Created(T),
+
+    /// The resource already existed.
+    Existing(T),
+}
+
+impl<T> Provisioned<T> {
+    /// Returns whether the resource was created.
+    pub fn is_created(&self) -> bool {
+        matches!(self, Provisioned::Created(_))
+    }
+
+    /// Returns the resource.
+    pub fn into_inner(self) -> T {
+        match self {
+            Provisioned::Created(resource) | Provisioned::Existing(resource) => resource,
+        }
+    }
+}
+
+impl<T> AsRef<T> for Provisioned<T> {
+    fn as_ref(&self) -> &T {
+        match self {
+            Provisioned::Created(resource) | Provisioned::Existing(resource) => resource,
+        }
+    }
+}
+
+/// The response for [`ProvisionFromProfile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProvisionFromProfileResponse {
+    /// The profile was linked to a user who is a member of the profile's organization.
+    Linked {
+        /// The user with the profile's email address.
+        user: Provisioned<User>,
+
+        /// The membership of the user in the profile's organization.
+        ///
+        /// An existing membership is returned with whatever status it has, so check
+        /// [`ProvisionFromProfileResponse::organization_membership_status`] before
+        /// granting access.
+        organization_membership: Provisioned<OrganizationMembership>,
+    },
+
+    /// A user with the profile's email address already exists, but the email address is
+    /// not on one of the organization's domains, so it cannot be trusted to identify
+    /// that user. The profile was not linked and no membership was created.
+    ExistingUserNotLinked {
+        /// The existing user with the profile's email address.
+        user: User,
+    },
+}
+
+impl ProvisionFromProfileResponse {
+    /// Returns the status of the user's membership in the profile's organization, or
+    /// `None` if the profile was not linked.
+    pub fn organization_membership_status(&self) -> Option<&OrganizationMembershipStatus> {
+        match self {
+            ProvisionFromProfileResponse::Linked {
+                organization_membership,
+                ..
+            } => Some(&organization_membership.as_ref().status),
+            ProvisionFromProfileResponse::ExistingUserNotLinked { .. } => None,
+        }
+    }
+}
+
+/// An error returned from [`ProvisionFromProfile`].
+#[derive(Debug, Error)]
+pub enum ProvisionFromProfileError {
+    /// The profile does not belong to an organization.
+    #[error("profile does not belong to an organization")]
+    NoOrganization,
+
+    /// The profile violates the organization's email-domain policy.
+    #[error("profile violates the organization's domain policy")]
+    DomainPolicy(#[from] DomainPolicyViolation),
+}
+
+impl From<ProvisionFromProfileError> for WorkOsError<ProvisionFromProfileError> {
+    fn from(err: ProvisionFromProfileError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+/// Just-in-time provisioning of users from SSO profiles.
+#[async_trait]
+pub trait ProvisionFromProfile {
+    /// Finds or creates the [`User`] with the email address of an SSO [`Profile`], and
+    /// finds or creates their [`OrganizationMembership`] in the profile's organization.
+    ///
+    /// The profile's organization is retrieved, and the profile is first checked against
+    /// it with [`DomainPolicy::check`]. A profile without an organization returns
+    /// [`ProvisionFromProfileError::NoOrganization`]. Its email address is
+    /// only trusted as verified when [`DomainPolicy::is_email_verified`] holds: only then
+    /// is an existing user linked, and a new user created with a verified email address.
+    /// Otherwise an existing user is returned as
+    /// [`ExistingUserNotLinked`](ProvisionFromProfileResponse::ExistingUserNotLinked).
+    ///
+    /// Provisioning is idempotent: when a concurrent login creates the user or the
+    /// membership first, the one it created is returned as
+    /// [`Existing`](Provisioned::Existing).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos::WorkOsResult;
+    /// # use workos::sso::Profile;
+    /// # use workos::user_management::*;
+    /// use workos::{ApiKey, WorkOs};
+    ///
+    /// # async fn run(profile: Profile) -> WorkOsResult<(), ProvisionFromProfileError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let response = workos
+    ///     .user_management()
+    ///     .provision_from_profile(&profile, Some("member"))
+    ///     .await?;
+    ///
+    /// match response {
+    ///     ProvisionFromProfileResponse::Linked {
+    ///         user,
+    ///         organization_membership,
+    ///     } if organization_membership.as_ref().status == OrganizationMembershipStatus::Active => {
+    ///         println!("Welcome, {}!", user.as_ref().email);
+    ///     }
+    ///     _ => eprintln!("sign in to link your account"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn provision_from_profile<TCustomAttributes: Sync>(
+        &self,
+        profile: &Profile<TCustomAttributes>,
+        role_slug: Option<&str>,
+    ) -> WorkOsResult<ProvisionFromProfileResponse, ProvisionFromProfileError>;
+}
+
+#[async_trait]
+impl ProvisionFromProfile for UserManagement<'_> {
+    async fn provision_from_profile<TCustomAttributes: Sync>(
+        &self,
+        profile: &Profile<TCustomAttributes>,
+        role_slug: Option<&str>,
+    ) -> WorkOsResult<ProvisionFromProfileResponse, ProvisionFromProfileError> {
+        let organization_id = profile
+            .organization_id
+            .as_ref()
+            .ok_or(ProvisionFromProfileError::NoOrganization)?;
+        let organization = self
+            .workos
+            .organizations()
+            .get_organization(organization_id)
+            .await
+            .map_err(|err| err.map_operation(|err| match err {}))?;
+
+        DomainPolicy::check(&organization, profile).map_err(ProvisionFromProfileError::from)?;
+        let email_verified = DomainPolicy::is_email_verified(&organization, profile);
+
+        let user = match provision_user(self, profile, email_verified).await? {
+            Provisioned::Existing(user) if !email_verified => {
+                return Ok(ProvisionFromProfileResponse::ExistingUserNotLinked { user });
+            }
+            user => user,
+        };
+        let organization_membership =
+            provision_organization_membership(self, &user.as_ref().id, &organization.id, role_slug)
+                .await?;
+
+        Ok(ProvisionFromProfileResponse::Linked {
+            user,
+            organization_membership,
+        })
+    }
+}
+
+async fn provision_user<TCustomAttributes>(
+    user_management: &UserManagement<'_>,
+    profile: &Profile<TCustomAttributes>,
+    email_verified: bool,
+) -> WorkOsResult<Provisioned<User>, ProvisionFromProfileError> {
+    if let Some(user) = find_user(user_management, &profile.email).await? {
+        return Ok(Provisioned::Existing(user));
+    }
+
+    let created = user_management
+        .create_user(&CreateUserParams {
+            email: &profile.email,
+            password: None,
+            first_name: profile.first_name.as_deref(),
+            last_name: profile.last_name.as_deref(),
+            email_verified: email_verified.then_some(true),
+            external_id: None,
+        })
+        .await;
+
+    match created {
+        Ok(user) => Ok(Provisioned::Created(user)),
+        Err(err @ WorkOsError::RequestError(_)) => {
+            // A concurrent login may have created the user in the meantime.
+            match find_user(user_management, &profile.email).await? {
+                Some(user) => Ok(Provisioned::Existing(user)),
+                None => Err(err.map_operation(|err| match err {})),
+            }
+        }
+        Err(err) => Err(err.map_operation(|err| match err {})),
+    }
+}
+
+async fn find_user(
+    user_management: &UserManagement<'_>,
+    email: &str,
+) -> WorkOsResult<Option<User>, ProvisionFromProfileError> {
+    let users = user_management
+        .list_users(&ListUsersParams {
+            email: Some(email),
+            ..Default::default()
+        })
+        .await
+        .map_err(|err| err.map_operation(|err| match err {}))?;
+
+    Ok(users
+        .data
+        .into_iter()
+        .find(|user| user.email.eq_ignore_ascii_case(email)))
+}
+
+async fn provision_organization_membership(
+    user_management: &UserManagement<'_>,
+    user_id: &UserId,
+    organization_id: &OrganizationId,
+    role_slug: Option<&str>,
+) -> WorkOsResult<Provisioned<OrganizationMembership>, ProvisionFromProfileError> {
+    if let Some(organization_membership) =
+        find_organization_membership(user_management, user_id, organization_id).await?
+    {
+        return Ok(Provisioned::Existing(organization_membership));
+    }
+
+    let created = user_management
+        .create_organization_membership(&CreateOrganizationMembershipParams {
+            user_id,
+            organization_id,
+            role_slug,
+        })
+        .await;
+
+    match created {
+        Ok(organization_membership) => Ok(Provisioned::Created(organization_membership)),
+        Err(err @ WorkOsError::RequestError(_)) => {
+            // A concurrent login may have created the membership in the meantime.
+            match find_organization_membership(user_management, user_id, organization_id).await? {
+                Some(organization_membership) => Ok(Provisioned::Existing(organization_membership)),
+                None => Err(err.map_operation(|err| match err {})),
+            }
+        }
+        Err(err) => Err(err.map_operation(|err| match err {})),
+    }
+}
+
+async fn find_organization_membership(
+    user_management: &UserManagement<'_>,
+    user_id: &UserId,
+    organization_id: &OrganizationId,
+) -> WorkOsResult<Option<OrganizationMembership>, ProvisionFromProfileError> {
+    let organization_memberships = user_management
+        .list_organization_memberships(&ListOrganizationMembershipsParams {
+            organization_id,
+            user_id: Some(user_id),
+            pagination: Default::default(),
+        })
+        .await
+        .map_err(|err| err.map_operation(|err| match err {}))?;
+
+    Ok(organization_memberships
+        .data
+        .into_iter()
+        .find(|organization_membership| &organization_membership.user_id == user_id))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::sso::{ConnectionId, ConnectionType, ProfileId};
+    use crate::{ApiKey, KnownOrUnknown, RawAttributes, WorkOs};
+
+    async fn mock_organization(server: &mut mockito::ServerGuard) -> mockito::Mock {
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(200)
+            .with_body(
+                json!({
+                  "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                  "object": "organization",
+                  "name": "Foo Corp",
+                  "allow_profiles_outside_organization": true,
+                  "created_at": "2021-06-25T19:07:33.155Z",
+                  "updated_at": "2021-06-25T19:07:33.155Z",
+                  "domains": [
+                    {
+                      "domain": "foo-corp.com",
+                      "id": "org_domain_01EHZNVPK2QXHMVWCEDQEKY69A",
+                      "object": "organization_domain"
+                    }
+                  ]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await
+    }
+
+    fn profile(email: &str) -> Profile {
+        Profile {
+            id: ProfileId::from("prof_01DMC79VCBZ0NY2099737PSVF1"),
+            connection_id: ConnectionId::from("conn_01E4ZCR3C56J083X43JQXF3JK5"),
+            organization_id: Some(OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")),
+            connection_type: KnownOrUnknown::Known(ConnectionType::OktaSaml),
+            idp_id: "00u1a0ufowBJlzPlk357".to_string(),
+            email: email.to_string(),
+            first_name: Some("Marcelina".to_string()),
+            last_name: Some("Davis".to_string()),
+            role: None,
+            groups: None,
+            custom_attributes: HashMap::new(),
+            raw_attributes: RawAttributes(HashMap::new()),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_creates_the_user_and_the_organization_membership() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _organization_mock = mock_organization(&mut server).await;
+
+        let _list_users_mock = server
+            .mock("GET", "/user_management/users")
+            .match_query(Matcher::UrlEncoded(
+                "email".to_string(),
+                "marcelina@foo-corp.com".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _create_user_mock = server
+            .mock("POST", "/user_management/users")
+            .match_body(Matcher::Json(json!({
+                "email": "marcelina@foo-corp.com",
+                "first_name": "Marcelina",
+                "last_name": "Davis",
+                "email_verified": true
+            })))
+            .with_status(201)
+            .with_body(
+                json!({
+                  "object": "user",
+                  "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                  "email": "marcelina@foo-corp.com",
+                  "first_name": "Marcelina",
+                  "last_name": "Davis",
+                  "email_verified": true,
+                  "profile_picture_url": null,
+                  "last_sign_in_at": null,
+                  "external_id": null,
+                  "metadata": {},
+                  "created_at": "2021-06-25T19:07:33.155Z",
+                  "updated_at": "2021-06-25T19:07:33.155Z"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _list_organization_memberships_mock = server
+            .mock("GET", "/user_management/organization_memberships")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(
+                    "organization_id".to_string(),
+                    "org_01EHZNVPK3SFK441A1RGBFSHRT".to_string(),
+                ),
+                Matcher::UrlEncoded(
+                    "user_id".to_string(),
+                    "user_01E4ZCR3C56J083X43JQXF3JK5".to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _create_organization_membership_mock = server
+            .mock("POST", "/user_management/organization_memberships")
+            .match_body(Matcher::Json(json!({
+                "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                "role_slug": "member"
+            })))
+            .with_status(201)
+            .with_body(
+                json!({
+                  "object": "organization_membership",
+                  "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+                  "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                  "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                  "role": {
+                    "slug": "member"
+                  },
+                  "status": "active",
+                  "created_at": "2021-06-25T19:07:33.155Z",
+                  "updated_at": "2021-06-25T19:07:33.155Z"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = workos
+            .user_management()
+            .provision_from_profile(&profile("marcelina@foo-corp.com"), Some("member"))
+            .await
+            .unwrap();
+
+        assert_matches!(
+            response,
+            ProvisionFromProfileResponse::Linked {
+                user: Provisioned::Created(_),
+                organization_membership: Provisioned::Created(_),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_existing_user_and_reports_the_membership_status() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _organization_mock = mock_organization(&mut server).await;
+
+        let _list_users_mock = server
+            .mock("GET", "/user_management/users")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "user",
+                      "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                      "email": "marcelina@foo-corp.com",
+                      "first_name": "Marcelina",
+                      "last_name": "Davis",
+                      "email_verified": true,
+                      "profile_picture_url": null,
+                      "last_sign_in_at": null,
+                      "external_id": null,
+                      "metadata": {},
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _list_organization_memberships_mock = server
+            .mock("GET", "/user_management/organization_memberships")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "organization_membership",
+                      "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+                      "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                      "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                      "role": {
+                        "slug": "member"
+                      },
+                      "status": "inactive",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = workos
+            .user_management()
+            .provision_from_profile(&profile("marcelina@foo-corp.com"), Some("member"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.organization_membership_status(),
+            Some(&OrganizationMembershipStatus::Inactive)
+        );
+        assert_matches!(
+            response,
+            ProvisionFromProfileResponse::Linked {
+                user: Provisioned::Existing(user),
+                organization_membership: Provisioned::Existing(_),
+            } if user.id == UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5")
+        );
+    }
+
+    #[tokio::test]
+    async fn it_does_not_link_an_existing_user_to_an_unverified_email() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _organization_mock = mock_organization(&mut server).await;
+
+        let _list_users_mock = server
+            .mock("GET", "/user_management/users")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "user",
+                      "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                      "email": "marcelina@example.com",
+                      "first_name": "Marcelina",
+                      "last_name": "Davis",
+                      "email_verified": true,
+                      "profile_picture_url": null,
+                      "last_sign_in_at": null,
+                      "external_id": null,
+                      "metadata": {},
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let organization_memberships_mock = server
+            .mock("GET", "/user_management/organization_memberships")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let response = workos
+            .user_management()
+            .provision_from_profile(&profile("marcelina@example.com"), Some("member"))
+            .await
+            .unwrap();
+
+        organization_memberships_mock.assert_async().await;
+        assert_matches!(
+            response,
+            ProvisionFromProfileResponse::ExistingUserNotLinked { .. }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_creates_a_user_with_an_unverified_email() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _organization_mock = mock_organization(&mut server).await;
+
+        let _list_users_mock = server
+            .mock("GET", "/user_management/users")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _create_user_mock = server
+            .mock("POST", "/user_management/users")
+            .match_body(Matcher::Json(json!({
+                "email": "marcelina@example.com",
+                "first_name": "Marcelina",
+                "last_name": "Davis"
+            })))
+            .with_status(201)
+            .with_body(
+                json!({
+                  "object": "user",
+                  "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                  "email": "marcelina@foo-corp.com",
+                  "first_name": "Marcelina",
+                  "last_name": "Davis",
+                  "email_verified": false,
+                  "profile_picture_url": null,
+                  "last_sign_in_at": null,
+                  "external_id": null,
+                  "metadata": {},
+                  "created_at": "2021-06-25T19:07:33.155Z",
+                  "updated_at": "2021-06-25T19:07:33.155Z"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _list_organization_memberships_mock = server
+            .mock("GET", "/user_management/organization_memberships")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "organization_membership",
+                      "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+                      "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                      "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                      "role": {
+                        "slug": "member"
+                      },
+                      "status": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = workos
+            .user_management()
+            .provision_from_profile(&profile("marcelina@example.com"), None)
+            .await
+            .unwrap();
+
+        assert_matches!(
+            response,
+            ProvisionFromProfileResponse::Linked {
+                user: Provisioned::Created(_),
+                ..
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_user_created_by_a_concurrent_login() {
+        let mut server = mockito::Server::new_async().await;
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let _organization_mock = mock_organization(&mut server).await;
+
+        let _list_users_before_mock = server
+            .mock("GET", "/user_management/users")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let _list_users_after_mock = server
+            .mock("GET", "/user_management/users")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "user",
+                      "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                      "email": "marcelina@foo-corp.com",
+                      "first_name": "Marcelina",
+                      "last_name": "Davis",
+                      "email_verified": true,
+                      "profile_picture_url": null,
+                      "last_sign_in_at": null,
+                      "external_id": null,
+                      "metadata": {},
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _create_user_mock = server
+            .mock("POST", "/user_management/users")
+            .with_status(422)
+            .with_body(
+                json!({
+                    "code": "email_not_available",
+                    "message": "This email is not available."
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _list_organization_memberships_mock = server
+            .mock("GET", "/user_management/organization_memberships")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "object": "organization_membership",
+                      "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+                      "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                      "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                      "role": {
+                        "slug": "member"
+                      },
+                      "status": "active",
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z"
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = workos
+            .user_management()
+            .provision_from_profile(&profile("marcelina@foo-corp.com"), None)
+            .await
+            .unwrap();
+
+        assert_matches!(
+            response,
+            ProvisionFromProfileResponse::Linked {
+                user: Provisioned::Existing(_),
+                ..
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_the_profile_has_no_organization() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let mut profile = profile("marcelina@foo-corp.com");
+        profile.organization_id = None;
+
+        let result = workos
+            .user_management()
+            .provision_from_profile(&profile, None)
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(
+                ProvisionFromProfileError::NoOrganization
+            ))
+        )
+    }
+}
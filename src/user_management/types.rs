@@ -1,7 +1,21 @@
+mod access_token_claims;
+mod authentication_response;
+mod device_authorization;
+mod identity;
+mod impersonator;
 mod invitation;
 mod organization_membership;
+mod password_hash;
+mod session;
 mod user;
 
+pub use access_token_claims::*;
+pub use authentication_response::*;
+pub use device_authorization::*;
+pub use identity::*;
+pub use impersonator::*;
 pub use invitation::*;
 pub use organization_membership::*;
+pub use password_hash::*;
+pub use session::*;
 pub use user::*;
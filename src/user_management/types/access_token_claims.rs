@@ -0,0 +1,164 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::organizations::OrganizationId;
+use crate::user_management::UserId;
+
+/// The `act` (actor) claim of an access token, present when the session is being
+/// impersonated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActorClaim {
+    /// The email address of the WorkOS Dashboard user who is impersonating the user.
+    pub sub: String,
+}
+
+/// The claims of an access token issued by WorkOS User Management.
+///
+/// [WorkOS Docs: Access Token](https://workos.com/docs/user-management/sessions/access-token)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccessTokenClaims {
+    /// The ID of the user the token was issued to.
+    pub sub: UserId,
+
+    /// The ID of the session.
+    pub sid: String,
+
+    /// The issuer of the token.
+    pub iss: Option<String>,
+
+    /// The ID of the organization the session is scoped to.
+    pub org_id: Option<OrganizationId>,
+
+    /// The slug of the user's role within the organization.
+    pub role: Option<String>,
+
+    /// The permissions granted by the user's role.
+    #[serde(default)]
+    pub permissions: Vec<String>,
+
+    /// The actor impersonating the user, if any.
+    pub act: Option<ActorClaim>,
+
+    /// The time at which the token expires, as a Unix timestamp.
+    pub exp: i64,
+
+    /// The time at which the token was issued, as a Unix timestamp.
+    pub iat: i64,
+}
+
+/// An error returned from [`AccessTokenClaims::decode_unverified`].
+#[derive(Debug, Error)]
+pub enum DecodeAccessTokenError {
+    /// The token is not a well-formed JWT.
+    #[error("malformed token")]
+    Malformed,
+
+    /// The token payload is not valid Base64.
+    #[error("invalid token encoding")]
+    Base64(#[from] base64::DecodeError),
+
+    /// The token payload does not contain the expected claims.
+    #[error("invalid token claims")]
+    Claims(#[from] serde_json::Error),
+}
+
+impl AccessTokenClaims {
+    /// Decodes the claims of an access token **without verifying its signature**.
+    ///
+    /// Only use this on tokens that were received directly from WorkOS (e.g., in an
+    /// [`AuthenticationResponse`](crate::user_management::AuthenticationResponse)) or
+    /// whose signature has already been verified against the JWKS of your environment.
+    pub fn decode_unverified(token: &str) -> Result<Self, DecodeAccessTokenError> {
+        let mut segments = token.split('.');
+        let payload = match (segments.next(), segments.next(), segments.next()) {
+            (Some(_header), Some(payload), Some(_signature)) if segments.next().is_none() => {
+                payload
+            }
+            _ => return Err(DecodeAccessTokenError::Malformed),
+        };
+
+        let payload = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('='))?;
+
+        Ok(serde_json::from_slice(&payload)?)
+    }
+
+    /// Returns whether the session is being impersonated by a WorkOS Dashboard user.
+    pub fn is_impersonated(&self) -> bool {
+        self.act.is_some()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use super::*;
+
+    fn encode_token(claims: serde_json::Value) -> String {
+        format!(
+            "{header}.{payload}.signature",
+            header = URL_SAFE_NO_PAD.encode(json!({ "alg": "RS256" }).to_string()),
+            payload = URL_SAFE_NO_PAD.encode(claims.to_string())
+        )
+    }
+
+    #[test]
+    fn it_decodes_the_claims_of_an_impersonated_session() {
+        let token = encode_token(json!({
+            "iss": "https://api.workos.com",
+            "sub": "user_01E4ZCR3C56J083X43JQXF3JK5",
+            "sid": "session_01HQSXZGF8FHF7A9ZZFCW4387R",
+            "org_id": "org_01H945H0YD4F97JN9MATX7BYAG",
+            "role": "admin",
+            "permissions": ["posts:read", "posts:write"],
+            "act": { "sub": "support@workos.com" },
+            "exp": 1709193857,
+            "iat": 1709193557
+        }));
+
+        let claims = AccessTokenClaims::decode_unverified(&token).unwrap();
+
+        assert_eq!(
+            claims,
+            AccessTokenClaims {
+                sub: UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"),
+                sid: "session_01HQSXZGF8FHF7A9ZZFCW4387R".to_string(),
+                iss: Some("https://api.workos.com".to_string()),
+                org_id: Some(OrganizationId::from("org_01H945H0YD4F97JN9MATX7BYAG")),
+                role: Some("admin".to_string()),
+                permissions: vec!["posts:read".to_string(), "posts:write".to_string()],
+                act: Some(ActorClaim {
+                    sub: "support@workos.com".to_string()
+                }),
+                exp: 1709193857,
+                iat: 1709193557,
+            }
+        );
+        assert!(claims.is_impersonated())
+    }
+
+    #[test]
+    fn it_decodes_the_claims_of_a_regular_session() {
+        let token = encode_token(json!({
+            "sub": "user_01E4ZCR3C56J083X43JQXF3JK5",
+            "sid": "session_01HQSXZGF8FHF7A9ZZFCW4387R",
+            "exp": 1709193857,
+            "iat": 1709193557
+        }));
+
+        let claims = AccessTokenClaims::decode_unverified(&token).unwrap();
+
+        assert!(!claims.is_impersonated())
+    }
+
+    #[test]
+    fn it_returns_an_error_for_a_malformed_token() {
+        assert_matches!(
+            AccessTokenClaims::decode_unverified("not-a-jwt"),
+            Err(DecodeAccessTokenError::Malformed)
+        )
+    }
+}
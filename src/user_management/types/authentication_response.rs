@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+
+use crate::organizations::OrganizationId;
+use crate::sso::OauthTokens;
+use crate::user_management::{AccessTokenClaims, DecodeAccessTokenError, Impersonator, User};
+use crate::KnownOrUnknown;
+
+/// The method by which a user authenticated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuthenticationMethod {
+    /// Single Sign-On (SSO).
+    #[serde(rename = "SSO")]
+    Sso,
+
+    /// Email and password.
+    Password,
+
+    /// Passkey.
+    Passkey,
+
+    /// Apple OAuth.
+    #[serde(rename = "AppleOAuth")]
+    AppleOauth,
+
+    /// GitHub OAuth.
+    #[serde(rename = "GitHubOAuth")]
+    GitHubOauth,
+
+    /// Google OAuth.
+    #[serde(rename = "GoogleOAuth")]
+    GoogleOauth,
+
+    /// Microsoft OAuth.
+    #[serde(rename = "MicrosoftOAuth")]
+    MicrosoftOauth,
+
+    /// Magic Auth.
+    MagicAuth,
+
+    /// Impersonation by a WorkOS Dashboard user.
+    Impersonation,
+}
+
+/// The response returned when a [`User`] successfully authenticates.
+///
+/// [WorkOS Docs: Authenticate](https://workos.com/docs/reference/user-management/authentication)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthenticationResponse {
+    /// The authenticated user.
+    pub user: User,
+
+    /// The ID of the organization the user selected to sign in to.
+    pub organization_id: Option<OrganizationId>,
+
+    /// A JWT containing information about the session.
+    pub access_token: String,
+
+    /// Exchange this token for a new access token.
+    pub refresh_token: String,
+
+    /// The method by which the user authenticated.
+    pub authentication_method: Option<KnownOrUnknown<AuthenticationMethod, String>>,
+
+    /// The tokens issued by the OAuth provider, when the user authenticated through one.
+    pub oauth_tokens: Option<OauthTokens>,
+
+    /// The WorkOS Dashboard user who is impersonating the user, if any.
+    pub impersonator: Option<Impersonator>,
+}
+
+impl AuthenticationResponse {
+    /// Returns whether the session is being impersonated by a WorkOS Dashboard user.
+    pub fn is_impersonated(&self) -> bool {
+        self.impersonator.is_some()
+    }
+
+    /// Decodes the claims of the [`access_token`](Self::access_token).
+    ///
+    /// The signature is not verified, as the token was received directly from WorkOS.
+    pub fn access_token_claims(&self) -> Result<AccessTokenClaims, DecodeAccessTokenError> {
+        AccessTokenClaims::decode_unverified(&self.access_token)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_an_impersonated_authentication_response() {
+        let response: AuthenticationResponse = serde_json::from_str(
+            &json!({
+                "user": {
+                    "object": "user",
+                    "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                    "email": "marcelina.davis@example.com",
+                    "first_name": "Marcelina",
+                    "last_name": "Davis",
+                    "email_verified": true,
+                    "profile_picture_url": null,
+                    "last_sign_in_at": null,
+                    "external_id": null,
+                    "metadata": {},
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z"
+                },
+                "organization_id": "org_01H945H0YD4F97JN9MATX7BYAG",
+                "access_token": "eyJhb.nNzb19vaWRjX2tleV9.lc5Uk4yWVk5In0",
+                "refresh_token": "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                "authentication_method": "Impersonation",
+                "impersonator": {
+                    "email": "admin@foocorp.com",
+                    "reason": "Investigating an issue with the customer's account."
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert!(response.is_impersonated());
+        assert_eq!(
+            response.impersonator,
+            Some(Impersonator {
+                email: "admin@foocorp.com".to_string(),
+                reason: Some("Investigating an issue with the customer's account.".to_string()),
+            })
+        );
+        assert_eq!(
+            response.authentication_method,
+            Some(KnownOrUnknown::Known(AuthenticationMethod::Impersonation))
+        )
+    }
+}
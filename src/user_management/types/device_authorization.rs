@@ -0,0 +1,70 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// A device code issued by the OAuth 2.0 Device Authorization Grant.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct DeviceCode(String);
+
+impl Display for DeviceCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for DeviceCode {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for DeviceCode {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// The response of a device authorization request.
+///
+/// [WorkOS Docs: CLI Auth](https://workos.com/docs/user-management/cli-auth)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceAuthorization {
+    /// The code the device uses to poll for the result of the authorization.
+    pub device_code: DeviceCode,
+
+    /// The code the user enters at the [`verification_uri`](Self::verification_uri).
+    pub user_code: String,
+
+    /// The URI the user should visit to authorize the device.
+    pub verification_uri: String,
+
+    /// The [`verification_uri`](Self::verification_uri) with the
+    /// [`user_code`](Self::user_code) already filled in.
+    pub verification_uri_complete: Option<String>,
+
+    /// The lifetime of the device code, in seconds.
+    pub expires_in: u64,
+
+    /// The minimum amount of time, in seconds, to wait between polling requests.
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+}
+
+fn default_interval() -> u64 {
+    5
+}
+
+impl DeviceAuthorization {
+    /// Returns the lifetime of the device code, i.e., [`expires_in`](Self::expires_in) as a
+    /// [`Duration`].
+    pub fn lifetime(&self) -> Duration {
+        Duration::from_secs(self.expires_in)
+    }
+
+    /// Returns the minimum amount of time to wait between polling requests, i.e.,
+    /// [`interval`](Self::interval) as a [`Duration`].
+    pub fn polling_interval(&self) -> Duration {
+        Duration::from_secs(self.interval)
+    }
+}
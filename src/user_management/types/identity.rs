@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use crate::KnownOrUnknown;
+
+/// The type of an [`Identity`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IdentityType {
+    /// An identity from an OAuth provider.
+    #[serde(rename = "OAuth")]
+    Oauth,
+}
+
+/// The provider of an [`Identity`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IdentityProvider {
+    /// Apple OAuth.
+    #[serde(rename = "AppleOAuth")]
+    AppleOauth,
+
+    /// GitHub OAuth.
+    #[serde(rename = "GitHubOAuth")]
+    GitHubOauth,
+
+    /// Google OAuth.
+    #[serde(rename = "GoogleOAuth")]
+    GoogleOauth,
+
+    /// Microsoft OAuth.
+    #[serde(rename = "MicrosoftOAuth")]
+    MicrosoftOauth,
+}
+
+/// [WorkOS Docs: Identity](https://workos.com/docs/reference/user-management/identity)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Identity {
+    /// The unique ID of the user in the external identity provider.
+    pub idp_id: String,
+
+    /// The type of the identity.
+    pub r#type: KnownOrUnknown<IdentityType, String>,
+
+    /// The provider of the identity.
+    pub provider: KnownOrUnknown<IdentityProvider, String>,
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_an_identity() {
+        let identity: Identity = serde_json::from_str(
+            &json!({
+                "idp_id": "4F42ABDE-1E44-4B66-824A-5F733C037A6D",
+                "type": "OAuth",
+                "provider": "MicrosoftOAuth"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            identity,
+            Identity {
+                idp_id: "4F42ABDE-1E44-4B66-824A-5F733C037A6D".to_string(),
+                r#type: KnownOrUnknown::Known(IdentityType::Oauth),
+                provider: KnownOrUnknown::Known(IdentityProvider::MicrosoftOauth),
+            }
+        )
+    }
+
+    #[test]
+    fn it_deserializes_unknown_identity_providers() {
+        let identity: Identity = serde_json::from_str(
+            &json!({
+                "idp_id": "12345",
+                "type": "OAuth",
+                "provider": "SomeNewOAuth"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            identity.provider,
+            KnownOrUnknown::Unknown("SomeNewOAuth".to_string())
+        )
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// A WorkOS Dashboard user who is impersonating a [`User`](crate::user_management::User).
+///
+/// [WorkOS Docs: Impersonation](https://workos.com/docs/user-management/impersonation)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Impersonator {
+    /// The email address of the WorkOS Dashboard user who is impersonating the user.
+    pub email: String,
+
+    /// The justification the impersonator gave for impersonating the user.
+    pub reason: Option<String>,
+}
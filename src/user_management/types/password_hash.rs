@@ -0,0 +1,439 @@
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
+use thiserror::Error;
+
+/// The digest used by a [`PasswordHash::Pbkdf2`] hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Pbkdf2Digest {
+    /// SHA-1.
+    Sha1,
+
+    /// SHA-256.
+    Sha256,
+
+    /// SHA-512.
+    Sha512,
+}
+
+impl Pbkdf2Digest {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Pbkdf2Digest::Sha1 => "sha1",
+            Pbkdf2Digest::Sha256 => "sha256",
+            Pbkdf2Digest::Sha512 => "sha512",
+        }
+    }
+
+    fn from_str(digest: &str) -> Option<Self> {
+        match digest {
+            "sha1" => Some(Pbkdf2Digest::Sha1),
+            "sha256" => Some(Pbkdf2Digest::Sha256),
+            "sha512" => Some(Pbkdf2Digest::Sha512),
+            _ => None,
+        }
+    }
+}
+
+/// An error returned from [`PasswordHash::decode`].
+#[derive(Debug, Error)]
+pub enum PasswordHashError {
+    /// The `password_hash_type` is not supported.
+    #[error("unsupported password hash type: {0}")]
+    UnsupportedType(String),
+
+    /// The `password_hash` is not in the format expected for its type.
+    #[error("malformed {0} password hash")]
+    Malformed(&'static str),
+}
+
+#[derive(Deserialize)]
+struct PasswordHashFields {
+    password_hash: String,
+    password_hash_type: String,
+}
+
+impl TryFrom<PasswordHashFields> for PasswordHash {
+    type Error = PasswordHashError;
+
+    fn try_from(fields: PasswordHashFields) -> Result<Self, Self::Error> {
+        PasswordHash::decode(&fields.password_hash_type, &fields.password_hash)
+    }
+}
+
+/// An existing password hash to import for a [`User`](crate::user_management::User).
+///
+/// All salts and hashes are expected to already be Base64-encoded.
+///
+/// A `PasswordHash` is (de)serialized as the `password_hash` and `password_hash_type`
+/// fields sent to the WorkOS API:
+///
+/// ```json
+/// { "password_hash": "$pbkdf2-sha256$i=600000$c2FsdA$aGFzaA", "password_hash_type": "pbkdf2" }
+/// ```
+///
+/// [WorkOS Docs: Migrate from another service](https://workos.com/docs/migrate/other-services)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "PasswordHashFields")]
+pub enum PasswordHash {
+    /// A bcrypt hash, e.g., `$2b$10$...`.
+    Bcrypt {
+        /// The bcrypt hash, including its version, cost and salt.
+        hash: String,
+    },
+
+    /// A scrypt hash.
+    Scrypt {
+        /// The derived key.
+        hash: String,
+
+        /// The salt.
+        salt: String,
+
+        /// The CPU/memory cost, expressed as the base-2 logarithm of `N`.
+        cost: u32,
+
+        /// The block size (`r`).
+        block_size: u32,
+
+        /// The parallelization factor (`p`).
+        parallelism: u32,
+    },
+
+    /// A hash produced by Firebase Authentication's modified scrypt.
+    FirebaseScrypt {
+        /// The password hash.
+        hash: String,
+
+        /// The per-user salt.
+        salt: String,
+
+        /// The project's salt separator.
+        salt_separator: String,
+
+        /// The project's signer key.
+        signer_key: String,
+
+        /// The number of rounds.
+        rounds: u32,
+
+        /// The memory cost.
+        memory_cost: u32,
+    },
+
+    /// A salted SHA-1 hash, e.g., `{SSHA}...`.
+    Ssha {
+        /// The SSHA hash, including the `{SSHA}` prefix.
+        hash: String,
+    },
+
+    /// A PBKDF2 hash.
+    Pbkdf2 {
+        /// The derived key.
+        hash: String,
+
+        /// The salt.
+        salt: String,
+
+        /// The digest used as the pseudo-random function.
+        digest: Pbkdf2Digest,
+
+        /// The number of iterations.
+        iterations: u32,
+    },
+}
+
+impl PasswordHash {
+    /// Returns the value of the `password_hash_type` field for this hash.
+    pub fn hash_type(&self) -> &'static str {
+        match self {
+            PasswordHash::Bcrypt { .. } => "bcrypt",
+            PasswordHash::Scrypt { .. } => "scrypt",
+            PasswordHash::FirebaseScrypt { .. } => "firebase-scrypt",
+            PasswordHash::Ssha { .. } => "ssha",
+            PasswordHash::Pbkdf2 { .. } => "pbkdf2",
+        }
+    }
+
+    /// Returns the value of the `password_hash` field for this hash.
+    ///
+    /// Hashes that carry parameters are encoded in the
+    /// [PHC string format](https://github.com/P-H-C/phc-string-format/blob/master/phc-sf-spec.md).
+    pub fn encode(&self) -> String {
+        match self {
+            PasswordHash::Bcrypt { hash } | PasswordHash::Ssha { hash } => hash.clone(),
+            PasswordHash::Scrypt {
+                hash,
+                salt,
+                cost,
+                block_size,
+                parallelism,
+            } => format!("$scrypt$ln={cost},r={block_size},p={parallelism}${salt}${hash}"),
+            PasswordHash::FirebaseScrypt {
+                hash,
+                salt,
+                salt_separator,
+                signer_key,
+                rounds,
+                memory_cost,
+            } => format!(
+                "$firebase-scrypt$hash={hash}$salt={salt}$sk={signer_key}$ss={salt_separator}$r={rounds}$m={memory_cost}"
+            ),
+            PasswordHash::Pbkdf2 {
+                hash,
+                salt,
+                digest,
+                iterations,
+            } => format!(
+                "$pbkdf2-{digest}$i={iterations}${salt}${hash}",
+                digest = digest.as_str()
+            ),
+        }
+    }
+
+    /// Parses a hash from the values of the `password_hash_type` and `password_hash`
+    /// fields, as produced by [`PasswordHash::hash_type`] and [`PasswordHash::encode`].
+    pub fn decode(hash_type: &str, encoded: &str) -> Result<Self, PasswordHashError> {
+        match hash_type {
+            "bcrypt" => Ok(PasswordHash::Bcrypt {
+                hash: encoded.to_string(),
+            }),
+            "ssha" => Ok(PasswordHash::Ssha {
+                hash: encoded.to_string(),
+            }),
+            "scrypt" => decode_scrypt(encoded).ok_or(PasswordHashError::Malformed("scrypt")),
+            "firebase-scrypt" => decode_firebase_scrypt(encoded)
+                .ok_or(PasswordHashError::Malformed("firebase-scrypt")),
+            "pbkdf2" => decode_pbkdf2(encoded).ok_or(PasswordHashError::Malformed("pbkdf2")),
+            _ => Err(PasswordHashError::UnsupportedType(hash_type.to_string())),
+        }
+    }
+}
+
+fn decode_scrypt(encoded: &str) -> Option<PasswordHash> {
+    let [params, salt, hash] = phc_segments(encoded, "scrypt")?;
+
+    let mut cost = None;
+    let mut block_size = None;
+    let mut parallelism = None;
+    for param in params.split(',') {
+        match param.split_once('=')? {
+            ("ln", value) => cost = value.parse().ok(),
+            ("r", value) => block_size = value.parse().ok(),
+            ("p", value) => parallelism = value.parse().ok(),
+            _ => return None,
+        }
+    }
+
+    Some(PasswordHash::Scrypt {
+        hash: hash.to_string(),
+        salt: salt.to_string(),
+        cost: cost?,
+        block_size: block_size?,
+        parallelism: parallelism?,
+    })
+}
+
+fn decode_firebase_scrypt(encoded: &str) -> Option<PasswordHash> {
+    let mut segments = encoded.strip_prefix("$firebase-scrypt$")?.split('$');
+    let mut field = |name: &str| {
+        segments
+            .next()?
+            .strip_prefix(name)?
+            .strip_prefix('=')
+            .map(str::to_string)
+    };
+
+    let hash = field("hash")?;
+    let salt = field("salt")?;
+    let signer_key = field("sk")?;
+    let salt_separator = field("ss")?;
+    let rounds = field("r")?.parse().ok()?;
+    let memory_cost = field("m")?.parse().ok()?;
+
+    if segments.next().is_some() {
+        return None;
+    }
+
+    Some(PasswordHash::FirebaseScrypt {
+        hash,
+        salt,
+        salt_separator,
+        signer_key,
+        rounds,
+        memory_cost,
+    })
+}
+
+fn decode_pbkdf2(encoded: &str) -> Option<PasswordHash> {
+    let digest = encoded.strip_prefix("$pbkdf2-")?.split('$').next()?;
+    let [iterations, salt, hash] = phc_segments(encoded, &format!("pbkdf2-{digest}"))?;
+
+    Some(PasswordHash::Pbkdf2 {
+        hash: hash.to_string(),
+        salt: salt.to_string(),
+        digest: Pbkdf2Digest::from_str(digest)?,
+        iterations: iterations.strip_prefix("i=")?.parse().ok()?,
+    })
+}
+
+/// Splits a `$<id>$<params>$<salt>$<hash>` PHC string into its last three segments.
+fn phc_segments<'a>(encoded: &'a str, id: &str) -> Option<[&'a str; 3]> {
+    let rest = encoded
+        .strip_prefix('$')?
+        .strip_prefix(id)?
+        .strip_prefix('$')?;
+    let mut segments = rest.split('$');
+
+    let parts = [segments.next()?, segments.next()?, segments.next()?];
+    if segments.next().is_some() || parts.iter().any(|part| part.is_empty()) {
+        return None;
+    }
+
+    Some(parts)
+}
+
+impl Serialize for PasswordHash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("password_hash", &self.encode())?;
+        map.serialize_entry("password_hash_type", self.hash_type())?;
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_serializes_a_bcrypt_hash() {
+        let hash = PasswordHash::Bcrypt {
+            hash: "$2b$10$N9qo8uLOickgx2ZMRZoMyeIjZAgcfl7p92ldGxad68LJZdL17lhWy".to_string(),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&hash).unwrap(),
+            json!({
+                "password_hash": "$2b$10$N9qo8uLOickgx2ZMRZoMyeIjZAgcfl7p92ldGxad68LJZdL17lhWy",
+                "password_hash_type": "bcrypt"
+            })
+        )
+    }
+
+    #[test]
+    fn it_serializes_a_scrypt_hash_in_phc_format() {
+        let hash = PasswordHash::Scrypt {
+            hash: "aGFzaA".to_string(),
+            salt: "c2FsdA".to_string(),
+            cost: 15,
+            block_size: 8,
+            parallelism: 1,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&hash).unwrap(),
+            json!({
+                "password_hash": "$scrypt$ln=15,r=8,p=1$c2FsdA$aGFzaA",
+                "password_hash_type": "scrypt"
+            })
+        )
+    }
+
+    #[test]
+    fn it_serializes_a_firebase_scrypt_hash() {
+        let hash = PasswordHash::FirebaseScrypt {
+            hash: "aGFzaA==".to_string(),
+            salt: "c2FsdA==".to_string(),
+            salt_separator: "Bw==".to_string(),
+            signer_key: "c2lnbmVy".to_string(),
+            rounds: 8,
+            memory_cost: 14,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&hash).unwrap(),
+            json!({
+                "password_hash": "$firebase-scrypt$hash=aGFzaA==$salt=c2FsdA==$sk=c2lnbmVy$ss=Bw==$r=8$m=14",
+                "password_hash_type": "firebase-scrypt"
+            })
+        )
+    }
+
+    #[test]
+    fn it_serializes_a_pbkdf2_hash_in_phc_format() {
+        let hash = PasswordHash::Pbkdf2 {
+            hash: "aGFzaA".to_string(),
+            salt: "c2FsdA".to_string(),
+            digest: Pbkdf2Digest::Sha256,
+            iterations: 600000,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&hash).unwrap(),
+            json!({
+                "password_hash": "$pbkdf2-sha256$i=600000$c2FsdA$aGFzaA",
+                "password_hash_type": "pbkdf2"
+            })
+        )
+    }
+
+    #[test]
+    fn it_round_trips_every_password_hash_type() {
+        let hashes = [
+            PasswordHash::Bcrypt {
+                hash: "$2b$10$N9qo8uLOickgx2ZMRZoMyeIjZAgcfl7p92ldGxad68LJZdL17lhWy".to_string(),
+            },
+            PasswordHash::Scrypt {
+                hash: "aGFzaA".to_string(),
+                salt: "c2FsdA".to_string(),
+                cost: 15,
+                block_size: 8,
+                parallelism: 1,
+            },
+            PasswordHash::FirebaseScrypt {
+                hash: "aGFzaA==".to_string(),
+                salt: "c2FsdA==".to_string(),
+                salt_separator: "Bw==".to_string(),
+                signer_key: "c2lnbmVy".to_string(),
+                rounds: 8,
+                memory_cost: 14,
+            },
+            PasswordHash::Ssha {
+                hash: "{SSHA}W6ph5Mm5Pz8GgiULbPgzG37mj9g=".to_string(),
+            },
+            PasswordHash::Pbkdf2 {
+                hash: "aGFzaA".to_string(),
+                salt: "c2FsdA".to_string(),
+                digest: Pbkdf2Digest::Sha512,
+                iterations: 600000,
+            },
+        ];
+
+        for hash in hashes {
+            let value = serde_json::to_value(&hash).unwrap();
+
+            assert_eq!(serde_json::from_value::<PasswordHash>(value).unwrap(), hash);
+        }
+    }
+
+    #[test]
+    fn it_rejects_malformed_and_unsupported_password_hashes() {
+        let malformed = serde_json::from_value::<PasswordHash>(json!({
+            "password_hash": "$scrypt$ln=15,r=8$c2FsdA$aGFzaA",
+            "password_hash_type": "scrypt"
+        }));
+        let unsupported = PasswordHash::decode("md5", "5f4dcc3b5aa765d61d8327deb882cf99");
+
+        assert!(malformed.is_err());
+        assert!(matches!(
+            unsupported,
+            Err(PasswordHashError::UnsupportedType(hash_type)) if hash_type == "md5"
+        ));
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::organizations::OrganizationId;
+use crate::user_management::UserId;
+use crate::{KnownOrUnknown, Timestamp, Timestamps};
+
+/// The ID of a [`Session`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SessionId(String);
+
+impl Display for SessionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for SessionId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SessionId {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// The status of a [`Session`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionStatus {
+    /// The session is active.
+    Active,
+
+    /// The session has expired.
+    Expired,
+
+    /// The session was revoked.
+    Revoked,
+}
+
+/// [WorkOS Docs: Session](https://workos.com/docs/reference/user-management/session)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    /// The ID of the session.
+    pub id: SessionId,
+
+    /// The ID of the user the session belongs to.
+    pub user_id: UserId,
+
+    /// The ID of the organization the session is scoped to, if any.
+    pub organization_id: Option<OrganizationId>,
+
+    /// The status of the session.
+    pub status: KnownOrUnknown<SessionStatus, String>,
+
+    /// The IP address the session was created from.
+    pub ip_address: Option<String>,
+
+    /// The user agent the session was created with.
+    pub user_agent: Option<String>,
+
+    /// The timestamp when the session expires.
+    pub expires_at: Timestamp,
+
+    /// The timestamps for the session.
+    #[serde(flatten)]
+    pub timestamps: Timestamps,
+}
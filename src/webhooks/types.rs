@@ -1,9 +1,11 @@
 mod directory;
 mod events;
+mod field_change;
 mod webhook;
 mod webhook_event;
 
 pub use directory::*;
 pub use events::*;
+pub use field_change::*;
 pub use webhook::*;
 pub use webhook_event::*;
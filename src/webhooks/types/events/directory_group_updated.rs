@@ -4,6 +4,7 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::directory_sync::DirectoryGroup;
+use crate::webhooks::{change_set, ChangeSet, DirectoryGroupField};
 
 /// A [`DirectoryGroup`] with its previous attributes.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
@@ -16,6 +17,16 @@ pub struct DirectoryGroupWithPreviousAttributes {
     pub previous_attributes: HashMap<String, Value>,
 }
 
+impl DirectoryGroupWithPreviousAttributes {
+    /// Returns the changes to the directory group, mapping the previous attributes onto
+    /// [`DirectoryGroup`] fields where possible.
+    pub fn changes(&self) -> Result<ChangeSet<DirectoryGroupField>, serde_json::Error> {
+        let current = serde_json::to_value(&self.directory_group)?;
+
+        Ok(change_set(&self.previous_attributes, &current))
+    }
+}
+
 /// [WorkOS Docs: `dsync.group.updated` Webhook](https://workos.com/docs/reference/webhooks/directory-group#webhooks-dsync.group.updated)
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub struct DirectoryGroupUpdatedWebhook(pub DirectoryGroupWithPreviousAttributes);
@@ -29,7 +40,7 @@ mod test {
     use super::*;
     use crate::directory_sync::{DirectoryGroupId, DirectoryId};
     use crate::organizations::OrganizationId;
-    use crate::webhooks::{Webhook, WebhookEvent, WebhookId};
+    use crate::webhooks::{FieldChange, Webhook, WebhookEvent, WebhookId};
     use crate::{RawAttributes, Timestamp, Timestamps};
 
     #[test]
@@ -99,4 +110,32 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn it_returns_the_changes_to_the_directory_group() {
+        let directory_group: DirectoryGroupWithPreviousAttributes = serde_json::from_str(
+            &json!({
+              "id": "directory_group_01E1X5GPMMXF4T1DCERMVEEPVW",
+              "idp_id": "02grqrue4294w24",
+              "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "name": "Developers",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+              "previous_attributes": {
+                "name": "Software Engineers"
+              }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            directory_group.changes().unwrap().changes,
+            vec![FieldChange {
+                field: DirectoryGroupField::Name,
+                old: json!("Software Engineers"),
+                new: json!("Developers"),
+            }]
+        )
+    }
 }
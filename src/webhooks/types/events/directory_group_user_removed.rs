@@ -1,15 +1,18 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
+use serde_json::Value;
 
 use crate::directory_sync::{DirectoryGroup, DirectoryId, DirectoryUser};
 
 /// [WorkOS Docs: `dsync.group.user_removed` Webhook](https://workos.com/docs/reference/webhooks/directory-group#webhooks-dsync.group.user_removed)
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-pub struct DirectoryUserRemovedFromGroupWebhook {
+pub struct DirectoryUserRemovedFromGroupWebhook<TCustomAttributes = HashMap<String, Value>> {
     /// The directory ID.
     pub directory_id: DirectoryId,
 
     /// The directory user that was removed from the group.
-    pub user: DirectoryUser,
+    pub user: DirectoryUser<TCustomAttributes>,
 
     /// The directory group that the user was removed from.
     pub group: DirectoryGroup,
@@ -113,6 +116,7 @@ mod test {
                                 "org_01EZTR6WYX1A0DSE2CYMGXQ24Y"
                             )),
                             username: Some("eric@foo-corp.com".to_string()),
+                            email: None,
                             emails: vec![DirectoryUserEmail {
                                 primary: Some(true),
                                 r#type: Some("work".to_string()),
@@ -120,6 +124,9 @@ mod test {
                             }],
                             first_name: Some("Eric".to_string()),
                             last_name: Some("Schneider".to_string()),
+                            job_title: None,
+                            groups: vec![],
+                            role: None,
                             custom_attributes: expected_custom_attributes,
                             raw_attributes: RawAttributes(expected_user_raw_attributes),
                         },
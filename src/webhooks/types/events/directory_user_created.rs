@@ -1,10 +1,15 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
+use serde_json::Value;
 
 use crate::directory_sync::DirectoryUser;
 
 /// [WorkOS Docs: `dsync.user.created` Webhook](https://workos.com/docs/reference/webhooks/directory-user#webhooks-dsync.user.created)
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-pub struct DirectoryUserCreatedWebhook(pub DirectoryUser);
+pub struct DirectoryUserCreatedWebhook<TCustomAttributes = HashMap<String, Value>>(
+    pub DirectoryUser<TCustomAttributes>,
+);
 
 #[cfg(test)]
 mod test {
@@ -79,6 +84,7 @@ mod test {
                             "org_01EZTR6WYX1A0DSE2CYMGXQ24Y"
                         )),
                         username: Some("veda@foo-corp.com".to_string()),
+                        email: None,
                         emails: vec![DirectoryUserEmail {
                             primary: Some(true),
                             r#type: Some("work".to_string()),
@@ -86,6 +92,9 @@ mod test {
                         }],
                         first_name: Some("Lela".to_string()),
                         last_name: Some("Block".to_string()),
+                        job_title: None,
+                        groups: vec![],
+                        role: None,
                         custom_attributes: expected_custom_attributes,
                         raw_attributes: RawAttributes(expected_raw_attributes),
                     }
@@ -93,4 +102,51 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn it_deserializes_a_directory_user_created_webhook_with_a_provided_custom_attributes_type() {
+        #[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+        struct MyCustomAttributes {
+            department: String,
+        }
+
+        let webhook: Webhook<MyCustomAttributes> = serde_json::from_str(
+            &json!({
+              "id": "wh_07FKJ843CVE8F7BXQSPFH0M53V",
+              "data": {
+                "id": "directory_user_01E1X1B89NH8Z3SDFJR4H7RGX7",
+                "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+                "idp_id": "8931",
+                "emails": [],
+                "first_name": "Lela",
+                "last_name": "Block",
+                "username": "veda@foo-corp.com",
+                "state": "active",
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z",
+                "custom_attributes": {
+                  "department": "Engineering"
+                },
+                "raw_attributes": {}
+              },
+              "event": "dsync.user.created"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let WebhookEvent::DirectoryUserCreated(DirectoryUserCreatedWebhook(directory_user)) =
+            webhook.event
+        else {
+            panic!("expected a dsync.user.created event");
+        };
+
+        assert_eq!(
+            directory_user.custom_attributes,
+            MyCustomAttributes {
+                department: "Engineering".to_string()
+            }
+        )
+    }
 }
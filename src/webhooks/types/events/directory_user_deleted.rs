@@ -1,10 +1,15 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
+use serde_json::Value;
 
 use crate::directory_sync::DirectoryUser;
 
 /// [WorkOS Docs: `dsync.user.deleted` Webhook](https://workos.com/docs/reference/webhooks/directory-user#webhooks-dsync.user.deleted)
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-pub struct DirectoryUserDeletedWebhook(pub DirectoryUser);
+pub struct DirectoryUserDeletedWebhook<TCustomAttributes = HashMap<String, Value>>(
+    pub DirectoryUser<TCustomAttributes>,
+);
 
 #[cfg(test)]
 mod test {
@@ -82,6 +87,7 @@ mod test {
                             "org_01EZTR6WYX1A0DSE2CYMGXQ24Y"
                         )),
                         username: Some("veda@foo-corp.com".to_string()),
+                        email: None,
                         emails: vec![DirectoryUserEmail {
                             primary: Some(true),
                             r#type: Some("work".to_string()),
@@ -89,6 +95,9 @@ mod test {
                         }],
                         first_name: Some("Veda".to_string()),
                         last_name: Some("Block".to_string()),
+                        job_title: None,
+                        groups: vec![],
+                        role: None,
                         custom_attributes: expected_custom_attributes,
                         raw_attributes: RawAttributes(expected_raw_attributes),
                     }
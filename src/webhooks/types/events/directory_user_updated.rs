@@ -1,24 +1,39 @@
 use std::collections::HashMap;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::directory_sync::DirectoryUser;
+use crate::webhooks::{change_set, ChangeSet, DirectoryUserField};
 
 /// A [`DirectoryUser`] with their previous attributes.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-pub struct DirectoryUserWithPreviousAttributes {
+pub struct DirectoryUserWithPreviousAttributes<TCustomAttributes = HashMap<String, Value>> {
     /// The directory user.
     #[serde(flatten)]
-    pub directory_user: DirectoryUser,
+    pub directory_user: DirectoryUser<TCustomAttributes>,
 
     /// The previous values for any attributes that were updated.
     pub previous_attributes: HashMap<String, Value>,
 }
 
+impl<TCustomAttributes: Serialize> DirectoryUserWithPreviousAttributes<TCustomAttributes> {
+    /// Returns the changes to the directory user, mapping the previous attributes onto
+    /// [`DirectoryUser`] fields where possible.
+    ///
+    /// Fails if the custom attributes cannot be serialized.
+    pub fn changes(&self) -> Result<ChangeSet<DirectoryUserField>, serde_json::Error> {
+        let current = serde_json::to_value(&self.directory_user)?;
+
+        Ok(change_set(&self.previous_attributes, &current))
+    }
+}
+
 /// [WorkOS Docs: `dsync.user.updated` Webhook](https://workos.com/docs/reference/webhooks/directory-user#webhooks-dsync.user.updated)
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-pub struct DirectoryUserUpdatedWebhook(pub DirectoryUserWithPreviousAttributes);
+pub struct DirectoryUserUpdatedWebhook<TCustomAttributes = HashMap<String, Value>>(
+    pub DirectoryUserWithPreviousAttributes<TCustomAttributes>,
+);
 
 #[cfg(test)]
 mod test {
@@ -31,7 +46,7 @@ mod test {
         DirectoryId, DirectoryUserEmail, DirectoryUserId, DirectoryUserState,
     };
     use crate::organizations::OrganizationId;
-    use crate::webhooks::{Webhook, WebhookEvent, WebhookId};
+    use crate::webhooks::{FieldChange, Webhook, WebhookEvent, WebhookId};
     use crate::{KnownOrUnknown, RawAttributes, Timestamp, Timestamps};
 
     #[test]
@@ -109,6 +124,7 @@ mod test {
                                 "org_01EZTR6WYX1A0DSE2CYMGXQ24Y"
                             )),
                             username: Some("veda@example.com".to_string()),
+                            email: None,
                             emails: vec![DirectoryUserEmail {
                                 primary: Some(true),
                                 r#type: Some("work".to_string()),
@@ -116,6 +132,9 @@ mod test {
                             }],
                             first_name: Some("Veda".to_string()),
                             last_name: Some("Block".to_string()),
+                            job_title: None,
+                            groups: vec![],
+                            role: None,
                             custom_attributes: expected_custom_attributes,
                             raw_attributes: RawAttributes(expected_raw_attributes),
                         },
@@ -125,4 +144,68 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn it_returns_the_changes_to_the_directory_user() {
+        let directory_user: DirectoryUserWithPreviousAttributes = serde_json::from_str(
+            &json!({
+              "id": "directory_user_01E1X1B89NH8Z3SDFJR4H7RGX7",
+              "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "idp_id": "8931",
+              "emails": [],
+              "first_name": "Lela",
+              "last_name": "Block",
+              "job_title": "Software Engineer",
+              "username": "veda@foo-corp.com",
+              "state": "active",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+              "raw_attributes": {
+                "title": "Software Engineer",
+                "costCenter": "R&D"
+              },
+              "custom_attributes": {},
+              "previous_attributes": {
+                "lastName": "Cube",
+                "job_title": "Intern",
+                "raw_attributes": {
+                  "costCenter": "Operations"
+                },
+                "nickName": "Lee"
+              }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            directory_user.changes().unwrap(),
+            ChangeSet {
+                changes: vec![
+                    FieldChange {
+                        field: DirectoryUserField::JobTitle,
+                        old: json!("Intern"),
+                        new: json!("Software Engineer"),
+                    },
+                    FieldChange {
+                        field: DirectoryUserField::LastName,
+                        old: json!("Cube"),
+                        new: json!("Block"),
+                    },
+                ],
+                unmapped: vec![
+                    FieldChange {
+                        field: "nickName".to_string(),
+                        old: json!("Lee"),
+                        new: Value::Null,
+                    },
+                    FieldChange {
+                        field: "costCenter".to_string(),
+                        old: json!("Operations"),
+                        new: json!("R&D"),
+                    },
+                ],
+            }
+        )
+    }
 }
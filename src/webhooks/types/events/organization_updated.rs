@@ -1,18 +1,46 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
+use serde_json::Value;
 
 use crate::organizations::Organization;
+use crate::webhooks::{change_set, ChangeSet, OrganizationField};
+
+/// An [`Organization`] with its previous attributes.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct OrganizationWithPreviousAttributes {
+    /// The organization.
+    #[serde(flatten)]
+    pub organization: Organization,
+
+    /// The previous values for any attributes that were updated.
+    #[serde(default)]
+    pub previous_attributes: HashMap<String, Value>,
+}
+
+impl OrganizationWithPreviousAttributes {
+    /// Returns the changes to the organization, mapping the previous attributes onto
+    /// [`Organization`] fields where possible.
+    pub fn changes(&self) -> Result<ChangeSet<OrganizationField>, serde_json::Error> {
+        let current = serde_json::to_value(&self.organization)?;
+
+        Ok(change_set(&self.previous_attributes, &current))
+    }
+}
 
 /// [WorkOS Docs: `organization.updated` Webhook](https://workos.com/docs/events/organization#organization-updated)
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-pub struct OrganizationUpdatedWebhook(pub Organization);
+pub struct OrganizationUpdatedWebhook(pub OrganizationWithPreviousAttributes);
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashMap;
+
     use serde_json::json;
 
     use super::*;
     use crate::organizations::{OrganizationDomain, OrganizationDomainId, OrganizationId};
-    use crate::webhooks::{Webhook, WebhookEvent, WebhookId};
+    use crate::webhooks::{FieldChange, Webhook, WebhookEvent, WebhookId};
     use crate::{Timestamp, Timestamps};
 
     #[test]
@@ -51,31 +79,73 @@ mod test {
             Webhook {
                 id: WebhookId::from("wh_01G699XH8F3MAJJWSHZFQ3WWVX"),
                 event: WebhookEvent::OrganizationUpdated(OrganizationUpdatedWebhook(
-                    Organization {
-                        id: OrganizationId::from("org_01EHWNCE74X7JSDV0X3SZ3KJNY"),
-                        name: "Foo Corp Updated".to_string(),
-                        allow_profiles_outside_organization: true,
-                        domains: vec![
-                            OrganizationDomain {
-                                id: OrganizationDomainId::from(
-                                    "org_domain_01EHWNFTAFCF3CQAE5A9Q0P1YB"
-                                ),
-                                domain: "foo-corp.com".to_string()
-                            },
-                            OrganizationDomain {
-                                id: OrganizationDomainId::from(
-                                    "org_domain_02EHWNFTAFCF3CQAE5A9Q0P1YC"
-                                ),
-                                domain: "foo-corp.org".to_string()
+                    OrganizationWithPreviousAttributes {
+                        organization: Organization {
+                            id: OrganizationId::from("org_01EHWNCE74X7JSDV0X3SZ3KJNY"),
+                            name: "Foo Corp Updated".to_string(),
+                            allow_profiles_outside_organization: true,
+                            domains: vec![
+                                OrganizationDomain {
+                                    id: OrganizationDomainId::from(
+                                        "org_domain_01EHWNFTAFCF3CQAE5A9Q0P1YB"
+                                    ),
+                                    domain: "foo-corp.com".to_string()
+                                },
+                                OrganizationDomain {
+                                    id: OrganizationDomainId::from(
+                                        "org_domain_02EHWNFTAFCF3CQAE5A9Q0P1YC"
+                                    ),
+                                    domain: "foo-corp.org".to_string()
+                                }
+                            ],
+                            timestamps: Timestamps {
+                                created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z")
+                                    .unwrap(),
+                                updated_at: Timestamp::try_from("2021-06-25T19:10:45.200Z")
+                                    .unwrap()
                             }
-                        ],
-                        timestamps: Timestamps {
-                            created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
-                            updated_at: Timestamp::try_from("2021-06-25T19:10:45.200Z").unwrap()
-                        }
+                        },
+                        previous_attributes: HashMap::new(),
                     }
                 ))
             }
         )
     }
+
+    #[test]
+    fn it_returns_the_changes_to_the_organization() {
+        let organization: OrganizationWithPreviousAttributes = serde_json::from_str(
+            &json!({
+              "object": "organization",
+              "id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+              "name": "Foo Corp Updated",
+              "allow_profiles_outside_organization": true,
+              "domains": [],
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:10:45.200Z",
+              "previous_attributes": {
+                "name": "Foo Corp",
+                "allowProfilesOutsideOrganization": false
+              }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            organization.changes().unwrap().changes,
+            vec![
+                FieldChange {
+                    field: OrganizationField::AllowProfilesOutsideOrganization,
+                    old: json!(false),
+                    new: json!(true),
+                },
+                FieldChange {
+                    field: OrganizationField::Name,
+                    old: json!("Foo Corp"),
+                    new: json!("Foo Corp Updated"),
+                },
+            ]
+        )
+    }
 }
@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+/// A change to a single field of a resource, from an `*.updated` webhook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange<F> {
+    /// The field that changed.
+    pub field: F,
+
+    /// The previous value of the field.
+    pub old: Value,
+
+    /// The new value of the field, or [`Value::Null`] if it is not known.
+    pub new: Value,
+}
+
+/// The changes from an `*.updated` webhook.
+///
+/// Previous attributes that can be mapped onto a field of the resource are in
+/// [`changes`](ChangeSet::changes). Any other previous attributes, e.g., provider-specific
+/// raw attributes, are in [`unmapped`](ChangeSet::unmapped) under their original key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeSet<F> {
+    /// The changes to fields of the resource.
+    pub changes: Vec<FieldChange<F>>,
+
+    /// The changes to attributes that do not map onto a field of the resource.
+    pub unmapped: Vec<FieldChange<String>>,
+}
+
+impl<F> ChangeSet<F> {
+    /// Returns whether there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.unmapped.is_empty()
+    }
+}
+
+/// A field of a [`DirectoryUser`](crate::directory_sync::DirectoryUser).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DirectoryUserField {
+    /// [`username`](crate::directory_sync::DirectoryUser::username).
+    Username,
+
+    /// [`email`](crate::directory_sync::DirectoryUser::email).
+    Email,
+
+    /// [`emails`](crate::directory_sync::DirectoryUser::emails).
+    Emails,
+
+    /// [`first_name`](crate::directory_sync::DirectoryUser::first_name).
+    FirstName,
+
+    /// [`last_name`](crate::directory_sync::DirectoryUser::last_name).
+    LastName,
+
+    /// [`job_title`](crate::directory_sync::DirectoryUser::job_title).
+    JobTitle,
+
+    /// [`groups`](crate::directory_sync::DirectoryUser::groups).
+    Groups,
+
+    /// [`role`](crate::directory_sync::DirectoryUser::role).
+    Role,
+
+    /// [`state`](crate::directory_sync::DirectoryUser::state).
+    State,
+
+    /// [`custom_attributes`](crate::directory_sync::DirectoryUser::custom_attributes).
+    CustomAttributes,
+}
+
+/// A field of a [`DirectoryGroup`](crate::directory_sync::DirectoryGroup).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DirectoryGroupField {
+    /// [`idp_id`](crate::directory_sync::DirectoryGroup::idp_id).
+    IdpId,
+
+    /// [`name`](crate::directory_sync::DirectoryGroup::name).
+    Name,
+}
+
+/// A field of an [`Organization`](crate::organizations::Organization).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrganizationField {
+    /// [`name`](crate::organizations::Organization::name).
+    Name,
+
+    /// [`allow_profiles_outside_organization`](crate::organizations::Organization::allow_profiles_outside_organization).
+    AllowProfilesOutsideOrganization,
+
+    /// [`domains`](crate::organizations::Organization::domains).
+    Domains,
+}
+
+/// A field that previous attributes can be mapped onto.
+pub(crate) trait ChangeField: Sized {
+    /// Returns the field for a key in the previous attributes.
+    ///
+    /// Keys are matched ignoring case and underscores, so that both `last_name` and
+    /// provider-specific keys like `lastName` match.
+    fn from_key(key: &str) -> Option<Self>;
+
+    /// Returns the key of the field in the serialized resource.
+    fn key(&self) -> &'static str;
+}
+
+impl ChangeField for DirectoryUserField {
+    fn from_key(key: &str) -> Option<Self> {
+        match normalize_key(key).as_str() {
+            "username" => Some(Self::Username),
+            "email" | "primaryemail" => Some(Self::Email),
+            "emails" => Some(Self::Emails),
+            "firstname" | "givenname" => Some(Self::FirstName),
+            "lastname" | "familyname" | "surname" => Some(Self::LastName),
+            "jobtitle" | "title" => Some(Self::JobTitle),
+            "groups" => Some(Self::Groups),
+            "role" => Some(Self::Role),
+            "state" => Some(Self::State),
+            "customattributes" => Some(Self::CustomAttributes),
+            _ => None,
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Self::Username => "username",
+            Self::Email => "email",
+            Self::Emails => "emails",
+            Self::FirstName => "first_name",
+            Self::LastName => "last_name",
+            Self::JobTitle => "job_title",
+            Self::Groups => "groups",
+            Self::Role => "role",
+            Self::State => "state",
+            Self::CustomAttributes => "custom_attributes",
+        }
+    }
+}
+
+impl ChangeField for DirectoryGroupField {
+    fn from_key(key: &str) -> Option<Self> {
+        match normalize_key(key).as_str() {
+            "idpid" => Some(Self::IdpId),
+            "name" | "displayname" => Some(Self::Name),
+            _ => None,
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Self::IdpId => "idp_id",
+            Self::Name => "name",
+        }
+    }
+}
+
+impl ChangeField for OrganizationField {
+    fn from_key(key: &str) -> Option<Self> {
+        match normalize_key(key).as_str() {
+            "name" => Some(Self::Name),
+            "allowprofilesoutsideorganization" => Some(Self::AllowProfilesOutsideOrganization),
+            "domains" => Some(Self::Domains),
+            _ => None,
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::AllowProfilesOutsideOrganization => "allow_profiles_outside_organization",
+            Self::Domains => "domains",
+        }
+    }
+}
+
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|char| *char != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Builds the [`ChangeSet`] from the previous attributes of a resource and the resource
+/// serialized to JSON.
+///
+/// Previous raw attributes, sent under `raw_attributes`, are reported as unmapped, with
+/// their new values taken from the raw attributes of the resource.
+pub(crate) fn change_set<F: ChangeField + PartialEq>(
+    previous_attributes: &HashMap<String, Value>,
+    current: &Value,
+) -> ChangeSet<F> {
+    let mut keys = previous_attributes.keys().collect::<Vec<_>>();
+    keys.sort();
+
+    let raw_attributes = current.get("raw_attributes");
+    let raw_value = |key: &str| {
+        raw_attributes
+            .and_then(|raw_attributes| raw_attributes.get(key))
+            .cloned()
+            .unwrap_or(Value::Null)
+    };
+
+    let mut change_set = ChangeSet {
+        changes: Vec::new(),
+        unmapped: Vec::new(),
+    };
+
+    for key in keys {
+        let old = &previous_attributes[key];
+
+        if key == "raw_attributes" {
+            if let Value::Object(previous_raw_attributes) = old {
+                let mut raw_keys = previous_raw_attributes.keys().collect::<Vec<_>>();
+                raw_keys.sort();
+
+                for raw_key in raw_keys {
+                    change_set.unmapped.push(FieldChange {
+                        field: raw_key.clone(),
+                        old: previous_raw_attributes[raw_key].clone(),
+                        new: raw_value(raw_key),
+                    });
+                }
+                continue;
+            }
+        }
+
+        match F::from_key(key) {
+            Some(field)
+                if !change_set
+                    .changes
+                    .iter()
+                    .any(|change| change.field == field) =>
+            {
+                let new = current.get(field.key()).cloned().unwrap_or(Value::Null);
+                change_set.changes.push(FieldChange {
+                    field,
+                    old: old.clone(),
+                    new,
+                });
+            }
+            _ => change_set.unmapped.push(FieldChange {
+                field: key.clone(),
+                old: old.clone(),
+                new: raw_value(key),
+            }),
+        }
+    }
+
+    change_set
+}
@@ -1,6 +1,8 @@
+use std::collections::HashMap;
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::webhooks::WebhookEvent;
 
@@ -27,12 +29,14 @@ impl From<&str> for WebhookId {
 }
 
 /// A WorkOS webhook.
+///
+/// See [`WebhookEvent`] for `TCustomAttributes`.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-pub struct Webhook {
+pub struct Webhook<TCustomAttributes = HashMap<String, Value>> {
     /// The ID of the webhook.
     pub id: WebhookId,
 
     /// The webhook event.
     #[serde(flatten)]
-    pub event: WebhookEvent,
+    pub event: WebhookEvent<TCustomAttributes>,
 }
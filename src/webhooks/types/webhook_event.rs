@@ -1,11 +1,17 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
+use serde_json::Value;
 
 use super::events::*;
 
 /// The event of a [`Webhook`](crate::webhooks::Webhook).
+///
+/// The `dsync.user.*` and `dsync.group.user_*` events decode the custom attributes of
+/// the directory user into `TCustomAttributes`.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 #[serde(tag = "event", content = "data")]
-pub enum WebhookEvent {
+pub enum WebhookEvent<TCustomAttributes = HashMap<String, Value>> {
     /// [WorkOS Docs: `connection.activated` Webhook](https://workos.com/docs/reference/webhooks/connection#webhooks-sso.connection.activated)
     #[serde(rename = "connection.activated")]
     ConnectionActivated(ConnectionActivatedWebhook),
@@ -32,15 +38,15 @@ pub enum WebhookEvent {
 
     /// [WorkOS Docs: `dsync.user.created` Webhook](https://workos.com/docs/reference/webhooks/directory-user#webhooks-dsync.user.created)
     #[serde(rename = "dsync.user.created")]
-    DirectoryUserCreated(DirectoryUserCreatedWebhook),
+    DirectoryUserCreated(DirectoryUserCreatedWebhook<TCustomAttributes>),
 
     /// [WorkOS Docs: `dsync.user.created` Webhook](https://workos.com/docs/reference/webhooks/directory-user#webhooks-dsync.user.updated)
     #[serde(rename = "dsync.user.updated")]
-    DirectoryUserUpdated(DirectoryUserUpdatedWebhook),
+    DirectoryUserUpdated(DirectoryUserUpdatedWebhook<TCustomAttributes>),
 
     /// [WorkOS Docs: `dsync.user.deleted` Webhook](https://workos.com/docs/reference/webhooks/directory-user#webhooks-dsync.user.deleted)
     #[serde(rename = "dsync.user.deleted")]
-    DirectoryUserDeleted(DirectoryUserDeletedWebhook),
+    DirectoryUserDeleted(DirectoryUserDeletedWebhook<TCustomAttributes>),
 
     /// [WorkOS Docs: `dsync.group.created` Webhook](https://workos.com/docs/reference/webhooks/directory-group#webhooks-dsync.group.created)
     #[serde(rename = "dsync.group.created")]
@@ -56,11 +62,11 @@ pub enum WebhookEvent {
 
     /// [WorkOS Docs: `dsync.group.user_added` Webhook](https://workos.com/docs/reference/webhooks/directory-group#webhooks-dsync.group.user_added)
     #[serde(rename = "dsync.group.user_added")]
-    DirectoryUserAddedToGroup(DirectoryUserAddedToGroupWebhook),
+    DirectoryUserAddedToGroup(DirectoryUserAddedToGroupWebhook<TCustomAttributes>),
 
     /// [WorkOS Docs: `dsync.group.user_removed` Webhook](https://workos.com/docs/reference/webhooks/directory-group#webhooks-dsync.group.user_removed)
     #[serde(rename = "dsync.group.user_removed")]
-    DirectoryUserRemovedFromGroup(DirectoryUserRemovedFromGroupWebhook),
+    DirectoryUserRemovedFromGroup(DirectoryUserRemovedFromGroupWebhook<TCustomAttributes>),
 
     /// [WorkOS Docs: `organization.created` Webhook](https://workos.com/docs/events/organization#organization-created)
     #[serde(rename = "organization.created")]